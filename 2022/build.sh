@@ -1,3 +0,0 @@
-#!/usr/bin/env bash
-
-cargo build --release
@@ -1,6 +0,0 @@
-#!/usr/bin/env bash
-
-DAY=$1
-PART=$2
-
-./target/release/aoc2022 $DAY $PART
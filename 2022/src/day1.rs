@@ -1,42 +1,39 @@
-fn parse_input(input: &str) -> Vec<u32> {
-    input
-        .split("\n\n")
-        .map(|elf| {
-            elf.lines()
-                .map(|l| -> u32 { l.trim().parse().unwrap() })
-                .sum()
-        })
-        .collect()
-}
+use aoc_core::{Answer, Solution, SolveError};
 
-pub fn part1(input: &str) -> u32 {
-    let calories = parse_input(input);
+pub struct Day1;
 
-    calories
-        .into_iter()
-        .reduce(|max, e| if max < e { e } else { max })
-        .unwrap()
-}
+impl Solution for Day1 {
+    type Input = Vec<u32>;
+
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        Ok(input
+            .split("\n\n")
+            .map(|elf| elf.lines().map(|l| l.trim().parse::<u32>()).sum())
+            .collect::<Result<_, _>>()?)
+    }
 
-pub fn part2(input: &str) -> u32 {
-    let calories = parse_input(input);
+    fn part1(calories: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(calories.iter().max().copied().unwrap().into())
+    }
 
-    let top_three = calories
-        .into_iter()
-        .fold((0_u32, 0_u32, 0_u32), |mut maxes, e| {
-            if e > maxes.0 {
-                maxes.2 = maxes.1;
-                maxes.1 = maxes.0;
-                maxes.0 = e;
-            } else if e > maxes.1 {
-                maxes.2 = maxes.1;
-                maxes.1 = e;
-            } else if e > maxes.2 {
-                maxes.2 = e;
-            }
+    fn part2(calories: &Self::Input) -> Result<Answer, SolveError> {
+        let top_three = calories
+            .iter()
+            .fold((0_u32, 0_u32, 0_u32), |mut maxes, &e| {
+                if e > maxes.0 {
+                    maxes.2 = maxes.1;
+                    maxes.1 = maxes.0;
+                    maxes.0 = e;
+                } else if e > maxes.1 {
+                    maxes.2 = maxes.1;
+                    maxes.1 = e;
+                } else if e > maxes.2 {
+                    maxes.2 = e;
+                }
 
-            maxes
-        });
+                maxes
+            });
 
-    top_three.0 + top_three.1 + top_three.2
+        Ok((top_three.0 + top_three.1 + top_three.2).into())
+    }
 }
@@ -1,59 +1,63 @@
 mod crt;
 
-use self::crt::{Instruction, Crt};
-
-fn parse_input(input: &str) -> Vec<Instruction> {
-    input
-        .lines()
-        .map(|line| match line {
-            "noop" => Instruction::Noop,
-            addx => Instruction::AddX(addx[5..].parse().unwrap()),
-        })
-        .collect()
-}
+use aoc_core::{Answer, Solution, SolveError};
 
-pub fn part1(input: &str) -> i32 {
-    let instructions = parse_input(input);
+use self::crt::{Crt, Instruction};
 
-    let mut clock = 1;
-    let mut x = 1;
-    let mut next_point = 20;
-    let mut sum = 0;
+pub struct Day10;
 
-    for inst in instructions {
-        if clock == next_point || clock == next_point - 1 {
-            sum += x * next_point;
-            if next_point == 220 {
-                break;
-            }
+impl Solution for Day10 {
+    type Input = Vec<Instruction>;
 
-            next_point += 40;
-        }
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        Ok(input
+            .lines()
+            .map(|line| match line {
+                "noop" => Instruction::Noop,
+                addx => Instruction::AddX(addx[5..].parse().unwrap()),
+            })
+            .collect())
+    }
 
-        match inst {
-            Instruction::Noop => clock += 1,
-            Instruction::AddX(dx) => {
-                x += dx;
-                clock += 2;
+    fn part1(instructions: &Self::Input) -> Result<Answer, SolveError> {
+        let mut clock = 1;
+        let mut x = 1;
+        let mut next_point = 20;
+        let mut sum = 0;
+
+        for inst in instructions {
+            if clock == next_point || clock == next_point - 1 {
+                sum += x * next_point;
+                if next_point == 220 {
+                    break;
+                }
+
+                next_point += 40;
             }
-        }
-    }
 
-    sum
-}
+            match inst {
+                Instruction::Noop => clock += 1,
+                Instruction::AddX(dx) => {
+                    x += dx;
+                    clock += 2;
+                }
+            }
+        }
 
-pub fn part2(input: &str) -> Crt {
-    let instructions = parse_input(input);
+        Ok(sum.into())
+    }
 
-    let mut crt = Crt::new();
+    fn part2(instructions: &Self::Input) -> Result<Answer, SolveError> {
+        let mut crt = Crt::new();
 
-    instructions.iter().for_each(|inst| {
-        crt.tick();
-        if let Instruction::AddX(dx) = inst {
+        instructions.iter().for_each(|inst| {
             crt.tick();
-            crt.add_x(*dx);
-        }
-    });
+            if let Instruction::AddX(dx) = inst {
+                crt.tick();
+                crt.add_x(*dx);
+            }
+        });
 
-    crt
+        Ok(crt.into())
+    }
 }
@@ -1,4 +1,4 @@
-use std::fmt::Display;
+use aoc_core::Answer;
 
 pub enum Instruction {
     AddX(i32),
@@ -26,7 +26,7 @@ impl Crt {
         }
 
         self.pixel.1 += 1;
-        if self.pixel.1 % 40 == 0 {
+        if self.pixel.1.is_multiple_of(40) {
             self.pixel = (self.pixel.0 + 1, 0);
         }
     }
@@ -36,15 +36,14 @@ impl Crt {
     }
 }
 
-impl Display for Crt {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let out: String = self
-            .screen
-            .iter()
-            .map(|line| line.iter().collect())
-            .collect::<Vec<String>>()
-            .join("\n");
+impl From<Crt> for Answer {
+    /// The letters on the screen, or the screen itself if it doesn't show letters (like the example).
+    fn from(crt: Crt) -> Self {
+        let rows = crt.screen.map(|line| line.iter().collect::<String>());
 
-        write!(f, "\n{}", out)
+        match aoc_core::ocr(&rows) {
+            Some(letters) => Answer::Text(letters),
+            None => Answer::grid(rows),
+        }
     }
 }
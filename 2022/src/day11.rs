@@ -1,40 +1,53 @@
 mod monkey;
 
+use aoc_core::{math, Answer, Solution, SolveError};
+
 use self::monkey::Monkey;
 use std::{cell::RefCell, rc::Rc};
 
-pub fn part1(input: &str) -> usize {
-    let monkeys = parse_monkeys(input, Rc::new(|item: usize| item / 3));
-    simulate(&monkeys, 20);
-    let two_best = find_two_best(&monkeys);
+pub struct Day11;
 
-    two_best.0 * two_best.1
-}
+impl Solution for Day11 {
+    type Input = Vec<Monkey>;
+
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        Ok(input.split("\n\n").map(Monkey::parse).collect())
+    }
 
-pub fn part2(input: &str) -> usize {
-    let monkeys = parse_monkeys(input, Rc::new(|item: usize| item));
-    let prod = monkeys.iter().fold(1, |p, m| p * m.borrow().get_divisor());
-    let reducer = Rc::new(move |item: usize| item % prod);
+    fn part1(monkeys: &Self::Input) -> Result<Answer, SolveError> {
+        let monkeys = prepare_monkeys(monkeys, Rc::new(|item: usize| item / 3));
+        simulate(&monkeys, 20);
+        let two_best = find_two_best(&monkeys);
 
-    monkeys.iter().for_each(|m| {
-        let r = Rc::clone(&reducer);
-        m.borrow_mut().set_worry_reducer(r);
-    });
+        Ok((two_best.0 * two_best.1).into())
+    }
+
+    fn part2(monkeys: &Self::Input) -> Result<Answer, SolveError> {
+        let common = math::lcm_all(monkeys.iter().map(|m| m.get_divisor()));
+        let monkeys = prepare_monkeys(monkeys, Rc::new(move |item: usize| item % common));
 
-    simulate(&monkeys, 10000);
-    let two_best = find_two_best(&monkeys);
+        simulate(&monkeys, 10000);
+        let two_best = find_two_best(&monkeys);
 
-    two_best.0 * two_best.1
+        Ok((two_best.0 * two_best.1).into())
+    }
 }
 
-fn parse_monkeys(input: &str, reduce_worry: Rc<dyn Fn(usize) -> usize>) -> Vec<RefCell<Monkey>> {
-    input
-        .split("\n\n")
-        .map(|monkey_str| RefCell::new(Monkey::parse(monkey_str, Rc::clone(&reduce_worry))))
+fn prepare_monkeys(
+    monkeys: &[Monkey],
+    reduce_worry: Rc<dyn Fn(usize) -> usize>,
+) -> Vec<RefCell<Monkey>> {
+    monkeys
+        .iter()
+        .map(|monkey| {
+            let mut monkey = monkey.clone();
+            monkey.set_worry_reducer(Rc::clone(&reduce_worry));
+            RefCell::new(monkey)
+        })
         .collect()
 }
 
-fn simulate(monkeys: &Vec<RefCell<Monkey>>, rounds: u16) {
+fn simulate(monkeys: &[RefCell<Monkey>], rounds: u16) {
     for _ in 1..=rounds {
         monkeys.iter().for_each(|monkey| {
             let mut items = monkey.borrow_mut().throw_items();
@@ -45,7 +58,7 @@ fn simulate(monkeys: &Vec<RefCell<Monkey>>, rounds: u16) {
     }
 }
 
-fn find_two_best(monkeys: &Vec<RefCell<Monkey>>) -> (usize, usize) {
+fn find_two_best(monkeys: &[RefCell<Monkey>]) -> (usize, usize) {
     monkeys.iter().fold((0, 0), |mut maxes, monkey| {
         let inspected = monkey.borrow().get_inspected();
         if inspected > maxes.0 {
@@ -1,16 +1,17 @@
 use std::{collections::LinkedList, rc::Rc};
 
+#[derive(Clone)]
 pub struct Monkey {
     items: LinkedList<usize>,
     inspected: usize,
-    operation: Box<dyn Fn(usize) -> usize>,
-    test: Box<dyn Fn(usize) -> usize>,
+    operation: Rc<dyn Fn(usize) -> usize>,
+    test: Rc<dyn Fn(usize) -> usize>,
     reduce_worry: Rc<dyn Fn(usize) -> usize>,
     divisor: usize,
 }
 
 impl Monkey {
-    pub fn parse(monkey_string: &str, reduce_worry: Rc<dyn Fn(usize) -> usize>) -> Self {
+    pub fn parse(monkey_string: &str) -> Self {
         let mut iter = monkey_string.lines();
         iter.next();
 
@@ -23,7 +24,7 @@ impl Monkey {
         let operand = String::from(operation.next().unwrap());
         let rhs = String::from(operation.next().unwrap());
 
-        let operation = Box::new(move |old: usize| {
+        let operation = Rc::new(move |old: usize| {
             let rhs = match rhs.as_str() {
                 "old" => old,
                 num => num.parse().unwrap(),
@@ -40,14 +41,14 @@ impl Monkey {
         let t = iter.next().unwrap().trim()[25..].parse().unwrap();
         let f = iter.next().unwrap().trim()[26..].parse().unwrap();
 
-        let test = Box::new(move |item: usize| if item % divisor == 0 { t } else { f });
+        let test = Rc::new(move |item: usize| if item.is_multiple_of(divisor) { t } else { f });
 
         Self {
             items,
             inspected: 0,
             operation,
             test,
-            reduce_worry,
+            reduce_worry: Rc::new(|item| item),
             divisor,
         }
     }
@@ -80,4 +81,4 @@ impl Monkey {
     pub fn set_worry_reducer(&mut self, reducer: Rc<dyn Fn(usize) -> usize>) {
         self.reduce_worry = reducer
     }
-}
\ No newline at end of file
+}
@@ -1,30 +1,23 @@
-use std::collections::{HashSet, LinkedList};
+use std::collections::HashSet;
 
-fn parse_input(input: &str) -> Vec<Vec<char>> {
-    input.lines().map(|line| line.chars().collect()).collect()
-}
+use aoc_core::{search, Answer, Grid, Pos, Solution, SolveError};
 
-pub fn part1(input: &str) -> u16 {
-    let map = parse_input(input);
+pub struct Day12;
 
-    count_shortest(&map, 'S', HashSet::from(['E']), false)
-}
+impl Solution for Day12 {
+    type Input = Grid<char>;
 
-pub fn part2(input: &str) -> u16 {
-    let map = parse_input(input);
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        Ok(Grid::parse(input, |c| c))
+    }
 
-    count_shortest(&map, 'E', HashSet::from(['S', 'a']), true)
-}
+    fn part1(map: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(count_shortest(map, 'S', HashSet::from(['E']), false).into())
+    }
 
-fn find_cell(map: &Vec<Vec<char>>, target: char) -> (usize, usize) {
-    map.iter()
-        .enumerate()
-        .find_map(|(r, row)| {
-            row.iter()
-                .enumerate()
-                .find_map(|(c, cell)| if *cell == target { Some((r, c)) } else { None })
-        })
-        .unwrap()
+    fn part2(map: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(count_shortest(map, 'E', HashSet::from(['S', 'a']), true).into())
+    }
 }
 
 fn convert_tile(tile: char) -> char {
@@ -46,48 +39,18 @@ fn can_move(from: char, to: char, down: bool) -> bool {
     }
 }
 
-/// Don't run it on grids larger than usize - 1 rows or cols lmao.
-/// The usize overflows backwards, so the >= 0 comparison is unneeded.
-fn get_next(
-    visited: &HashSet<(usize, usize)>,
-    width: usize,
-    height: usize,
-    row: usize,
-    col: usize,
-) -> Vec<(usize, usize)> {
-    vec![
-        (row - 1, col),
-        (row + 1, col),
-        (row, col - 1),
-        (row, col + 1),
-    ]
-    .into_iter()
-    .filter(|(r, c)| *r < height && *c < width)
-    .filter(|point| !visited.contains(point))
-    .collect()
-}
-
-fn count_shortest(map: &Vec<Vec<char>>, start: char, ends: HashSet<char>, down: bool) -> u16 {
-    let start = find_cell(map, start);
-    let mut queue = LinkedList::from([(start.0, start.1, 0)]);
-    let mut visited = HashSet::from([start]);
-
-    let width = map[0].len();
-    let height = map.len();
-
-    while let Some((row, col, steps)) = queue.pop_front() {
-        if ends.contains(&map[row][col]) {
-            return steps;
-        }
-
-        get_next(&visited, width, height, row, col)
-            .into_iter()
-            .filter(|(r, c)| can_move(map[row][col], map[*r][*c], down))
-            .for_each(|(r, c)| {
-                visited.insert((r, c));
-                queue.push_back((r, c, steps + 1));
-            });
-    }
-
-    unreachable!()
+fn count_shortest(map: &Grid<char>, start: char, ends: HashSet<char>, down: bool) -> usize {
+    let start = map.position(|cell| *cell == start).unwrap();
+
+    search::bfs(
+        [start],
+        |pos| {
+            map.neighbors4(*pos)
+                .filter(|next| can_move(map[*pos], map[*next], down))
+                .collect::<Vec<Pos>>()
+        },
+        |pos| ends.contains(&map[*pos]),
+    )
+    .goal_distance()
+    .unwrap()
 }
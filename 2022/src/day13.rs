@@ -1,47 +1,57 @@
 mod packet;
 
-use self::packet::Packet;
+use aoc_core::{Answer, Solution, SolveError};
 
-pub fn part1(input: &str) -> usize {
-    let pairs: Vec<(Packet, Packet)> = input
-        .split("\n\n")
-        .map(|pair| {
-            let mut iter = pair.split("\n").map(|packet| Packet::parse(packet));
-            (iter.next().unwrap(), iter.next().unwrap())
-        })
-        .collect();
-
-    pairs
-        .iter()
-        .enumerate()
-        .fold(0, |sum, (i, (a, b))| sum + if a <= b { i + 1 } else { 0 })
-}
+use self::packet::Packet;
 
-pub fn part2(input: &str) -> usize {
-    let mut packets: Vec<Packet> = input
-        .lines()
-        .filter(|line| line.len() > 0)
-        .map(|packet| Packet::parse(packet))
-        .collect();
-
-    packets.push(Packet::parse("[[2]]"));
-    packets.push(Packet::parse("[[6]]"));
-    packets.sort();
-
-    let start = Packet::parse("[[2]]");
-    let end = Packet::parse("[[6]]");
-
-    let start = packets
-        .iter()
-        .enumerate()
-        .find_map(|(i, packet)| if *packet == start { Some(i + 1) } else { None })
-        .unwrap();
-
-    let end = packets
-        .iter()
-        .enumerate()
-        .find_map(|(i, packet)| if *packet == end { Some(i + 1) } else { None })
-        .unwrap();
-
-    start * end
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Vec<(Packet, Packet)>;
+
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        Ok(input
+            .split("\n\n")
+            .map(|pair| {
+                let mut iter = pair.lines().map(Packet::parse);
+                (iter.next().unwrap(), iter.next().unwrap())
+            })
+            .collect())
+    }
+
+    fn part1(pairs: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(pairs
+            .iter()
+            .enumerate()
+            .fold(0, |sum, (i, (a, b))| sum + if a <= b { i + 1 } else { 0 })
+            .into())
+    }
+
+    fn part2(pairs: &Self::Input) -> Result<Answer, SolveError> {
+        let mut packets: Vec<Packet> = pairs
+            .iter()
+            .flat_map(|(a, b)| [a.clone(), b.clone()])
+            .collect();
+
+        packets.push(Packet::parse("[[2]]"));
+        packets.push(Packet::parse("[[6]]"));
+        packets.sort();
+
+        let start = Packet::parse("[[2]]");
+        let end = Packet::parse("[[6]]");
+
+        let start = packets
+            .iter()
+            .enumerate()
+            .find_map(|(i, packet)| if *packet == start { Some(i + 1) } else { None })
+            .unwrap();
+
+        let end = packets
+            .iter()
+            .enumerate()
+            .find_map(|(i, packet)| if *packet == end { Some(i + 1) } else { None })
+            .unwrap();
+
+        Ok((start * end).into())
+    }
 }
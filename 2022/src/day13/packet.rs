@@ -1,19 +1,18 @@
-use std::{cell::RefCell, collections::LinkedList};
+use std::{cell::RefCell, cmp::Ordering, collections::LinkedList};
 
-#[derive(Debug, PartialEq, Eq, Ord)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Packet {
-    List(Vec<Box<Packet>>),
+    List(Vec<Packet>),
     Num(u8),
 }
 
 impl Packet {
     pub fn parse(input: &str) -> Self {
-        let mut iter = input.chars();
-        let stack: RefCell<LinkedList<Vec<Box<Packet>>>> = RefCell::new(LinkedList::new());
+        let stack: RefCell<LinkedList<Vec<Packet>>> = RefCell::new(LinkedList::new());
         let current_num = RefCell::new(String::new());
 
         let add_num = || {
-            if current_num.borrow().len() == 0 {
+            if current_num.borrow().is_empty() {
                 return;
             }
 
@@ -21,11 +20,11 @@ impl Packet {
                 .borrow_mut()
                 .back_mut()
                 .unwrap()
-                .push(Box::new(Packet::Num(current_num.borrow().parse().unwrap())));
+                .push(Packet::Num(current_num.borrow().parse().unwrap()));
             current_num.borrow_mut().clear();
         };
 
-        while let Some(c) = iter.next() {
+        for c in input.chars() {
             match c {
                 '[' => stack.borrow_mut().push_back(Vec::new()),
                 ']' => {
@@ -38,7 +37,7 @@ impl Packet {
                             .borrow_mut()
                             .back_mut()
                             .unwrap()
-                            .push(Box::new(Self::List(closed)));
+                            .push(Self::List(closed));
                     }
                 }
                 ',' => add_num(),
@@ -51,12 +50,18 @@ impl Packet {
 }
 
 impl PartialOrd for Packet {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Packet {
+    fn cmp(&self, other: &Self) -> Ordering {
         match (self, other) {
-            (Self::Num(a), Self::Num(b)) => a.partial_cmp(b),
-            (Self::List(a), Self::List(b)) => a.partial_cmp(b),
-            (Self::List(a), Self::Num(b)) => a.partial_cmp(&vec![Box::new(Self::Num(*b))]),
-            (Self::Num(a), Self::List(b)) => vec![Box::new(Self::Num(*a))].partial_cmp(b),
+            (Self::Num(a), Self::Num(b)) => a.cmp(b),
+            (Self::List(a), Self::List(b)) => a.cmp(b),
+            (Self::List(a), Self::Num(b)) => a.as_slice().cmp(&[Self::Num(*b)]),
+            (Self::Num(a), Self::List(b)) => [Self::Num(*a)].as_slice().cmp(b),
         }
     }
 }
@@ -69,12 +74,12 @@ mod tests {
     fn can_parse_packet() {
         let packet = "[1,[25,6,[7]]]";
         let expected = Packet::List(vec![
-            Box::new(Packet::Num(1)),
-            Box::new(Packet::List(vec![
-                Box::new(Packet::Num(25)),
-                Box::new(Packet::Num(6)),
-                Box::new(Packet::List(vec![Box::new(Packet::Num(7))])),
-            ])),
+            Packet::Num(1),
+            Packet::List(vec![
+                Packet::Num(25),
+                Packet::Num(6),
+                Packet::List(vec![Packet::Num(7)]),
+            ]),
         ]);
 
         assert_eq!(Packet::parse(packet), expected);
@@ -83,9 +88,7 @@ mod tests {
     #[test]
     fn can_parse_only_list_packet() {
         let packet = "[[[]]]";
-        let expected = Packet::List(vec![Box::new(Packet::List(vec![Box::new(Packet::List(
-            Vec::new(),
-        ))]))]);
+        let expected = Packet::List(vec![Packet::List(vec![Packet::List(Vec::new())])]);
 
         assert_eq!(Packet::parse(packet), expected);
     }
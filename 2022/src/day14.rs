@@ -1,78 +1,82 @@
-use std::{cell::RefCell, cmp, collections::HashSet};
-
-fn parse_input(input: &str) -> (RefCell<HashSet<(u16, u16)>>, u16) {
-    let mut filled = HashSet::new();
-    let mut bottom = 0;
-
-    input
-        .lines()
-        .map(|line| {
-            line.split(" -> ").map(|point| {
-                let point: Vec<u16> = point.split(',').map(|num| num.parse().unwrap()).collect();
-                (point[0], point[1])
+use std::cmp;
+
+use aoc_core::{Answer, Grid, Solution, SolveError};
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = (Grid<bool>, usize);
+
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        let formations: Vec<Vec<(usize, usize)>> = input
+            .lines()
+            .map(|line| {
+                line.split(" -> ")
+                    .map(|point| {
+                        let point: Vec<usize> =
+                            point.split(',').map(|num| num.parse().unwrap()).collect();
+                        (point[0], point[1])
+                    })
+                    .collect()
             })
-        })
-        .for_each(|mut formation| {
-            let mut last = formation.next().unwrap();
+            .collect();
 
-            if last.1 > bottom {
-                bottom = last.1;
-            }
+        let bottom = formations.iter().flatten().map(|(_, y)| *y).max().unwrap();
+        let right = formations.iter().flatten().map(|(x, _)| *x).max().unwrap();
+
+        // the pile is a triangle on the floor at bottom + 2, so it can't get wider than this, but
+        // rocks can reach further out
+        let width = cmp::max(right + 1, 500 + bottom + 3);
+        let mut filled = Grid::new(width, bottom + 3, false);
+
+        formations.iter().for_each(|formation| {
+            formation.windows(2).for_each(|line| {
+                let (last, current) = (line[0], line[1]);
 
-            while let Some(current) = formation.next() {
                 if last.0 == current.0 {
                     for y in cmp::min(last.1, current.1)..=cmp::max(last.1, current.1) {
-                        filled.insert((current.0, y));
+                        filled[(y, current.0)] = true;
                     }
                 } else {
                     for x in cmp::min(last.0, current.0)..=cmp::max(last.0, current.0) {
-                        filled.insert((x, current.1));
+                        filled[(current.1, x)] = true;
                     }
                 }
-
-                if current.1 > bottom {
-                    bottom = current.1;
-                }
-
-                last = current;
-            }
+            });
         });
 
-    (RefCell::new(filled), bottom)
-}
+        Ok((filled, bottom))
+    }
 
-pub fn part1(input: &str) -> u16 {
-    let (filled, bottom) = parse_input(input);
+    fn part1((filled, bottom): &Self::Input) -> Result<Answer, SolveError> {
+        let mut filled = filled.clone();
+
+        let mut i: u16 = 0;
+        while does_settle((500, 0), &mut filled, *bottom) {
+            i += 1;
+        }
 
-    let mut i = 0;
-    while does_settle((500, 0), &filled, bottom) {
-        i += 1;
+        Ok(i.into())
     }
 
-    i
-}
+    fn part2((filled, bottom): &Self::Input) -> Result<Answer, SolveError> {
+        let mut filled = filled.clone();
 
-pub fn part2(input: &str) -> u16 {
-    let (filled, bottom) = parse_input(input);
+        let mut i: u16 = 0;
+        while !filled[(0, 500)] {
+            does_settle((500, 0), &mut filled, bottom + 2);
+            i += 1;
+        }
 
-    let mut i = 0;
-    while !filled.borrow().contains(&(500, 0)) {
-        does_settle((500, 0), &filled, bottom + 2);
-        i += 1;
+        Ok(i.into())
     }
-
-    i
 }
 
-fn does_settle(
-    (mut x, mut y): (u16, u16),
-    filled: &RefCell<HashSet<(u16, u16)>>,
-    bottom: u16,
-) -> bool {
+fn does_settle((mut x, mut y): (usize, usize), filled: &mut Grid<bool>, bottom: usize) -> bool {
     y += 1;
-    if !filled.borrow().contains(&(x, y)) {
+    if !filled[(y, x)] {
         if y >= bottom {
-            filled.borrow_mut().insert((x, y - 1));
+            filled[(y - 1, x)] = true;
             return false;
         }
 
@@ -80,16 +84,31 @@ fn does_settle(
     }
 
     x -= 1;
-    if !filled.borrow().contains(&(x, y)) {
+    if !filled[(y, x)] {
         return does_settle((x, y), filled, bottom);
     }
 
     x += 2;
-    if !filled.borrow().contains(&(x, y)) {
+    if !filled[(y, x)] {
         return does_settle((x, y), filled, bottom);
     }
 
-    filled.borrow_mut().insert((x - 1, y - 1));
+    filled[(y - 1, x - 1)] = true;
 
     true
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_fit_rocks_wider_than_the_pile() {
+        let (filled, bottom) = Day14::parse("498,4 -> 502,4\n600,2 -> 700,2").unwrap();
+
+        assert_eq!(bottom, 4);
+        assert!(filled[(2, 700)]);
+        assert_eq!(Day14::part1(&(filled.clone(), bottom)), Ok(Answer::from(4)));
+        assert_eq!(Day14::part2(&(filled, bottom)), Ok(Answer::from(28)));
+    }
+}
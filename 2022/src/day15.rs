@@ -1,81 +1,83 @@
 mod sensor;
 
-use self::sensor::{Point, Sensor};
-use std::cmp::Ordering;
-
-fn parse_input(input: &str) -> Vec<Sensor> {
-    input
-        .lines()
-        .map(|line| {
-            let mut parts = line.split(": ");
-            let position = Point::parse(&parts.next().unwrap()[10..]);
-            let closest_beacon = Point::parse(&parts.next().unwrap()[21..]);
-            let beacon_distance = &position - &closest_beacon;
-
-            Sensor::new(position, closest_beacon, beacon_distance)
-        })
-        .collect()
-}
+use aoc_core::{Answer, Interval, IntervalSet, Solution, SolveError};
 
-pub fn part1(input: &str) -> u32 {
-    let sensors = parse_input(input);
+use self::sensor::{parse_point, Sensor};
 
-    find_combined_coverage(&sensors, 2000000, None, None, false)
-        .into_iter()
-        .fold(0, |sum, (min, max)| sum + (max - min) as u32 + 1)
-}
+/// The row part 1 counts covered spots in, the example uses row 10.
+pub const ROW: i32 = 2000000;
+/// Part 2's distress beacon has both coordinates in `0..=SEARCH_MAX`, the example uses 20.
+pub const SEARCH_MAX: i32 = 4000000;
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Vec<Sensor>;
 
-pub fn part2(input: &str) -> u64 {
-    let sensors = parse_input(input);
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        Ok(input
+            .lines()
+            .map(|line| {
+                let mut parts = line.split(": ");
+                let position = parse_point(&parts.next().unwrap()[10..]);
+                let closest_beacon = parse_point(&parts.next().unwrap()[21..]);
+                let beacon_distance = position.manhattan(&closest_beacon) as u32;
 
-    for row in 0..=4000000 {
-        let coverage = find_combined_coverage(&sensors, row, Some(0), Some(4000000), true);
+                Sensor::new(position, closest_beacon, beacon_distance)
+            })
+            .collect())
+    }
 
-        if coverage.len() > 1 {
-            return (coverage[0].1 + 1) as u64 * 4000000 + row as u64;
-        } else if coverage[0].0 == 1 {
-            return row as u64;
-        } else if coverage[0].1 == 3999999 {
-            return 4000000 * 4000000 + row as u64;
-        }
+    fn part1(sensors: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(count_without_beacon(sensors, ROW).into())
     }
 
-    unreachable!()
+    fn part2(sensors: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(tuning_frequency(sensors, SEARCH_MAX)
+            .ok_or_else(|| SolveError::Failed("every spot is covered by a sensor".to_owned()))?
+            .into())
+    }
 }
 
-fn find_combined_coverage(
-    sensors: &Vec<Sensor>,
-    row: i32,
-    min: Option<i32>,
-    max: Option<i32>,
-    include_beacons: bool,
-) -> Vec<(i32, i32)> {
-    let mut ranges: Vec<(i32, i32)> = sensors
+/// How many spots in `row` can't hold a beacon.
+pub fn count_without_beacon(sensors: &[Sensor], row: i32) -> i32 {
+    let beacons = sensors
         .iter()
-        .filter_map(|sensor| sensor.find_row_coverage(row, min, max, include_beacons))
+        .filter_map(|sensor| sensor.find_beacon_in_row(row))
+        .map(|x| Interval::inclusive(x, x))
         .collect();
 
-    ranges.sort_by(|a, b| match b.0.cmp(&a.0) {
-        Ordering::Equal => b.1.cmp(&a.1),
-        other => other,
-    });
-
-    let mut combined = Vec::new();
-    let mut current = ranges.pop().unwrap();
-
-    while let Some(range) = ranges.pop() {
-        if range.1 <= current.1 {
-            continue;
-        }
-
-        if range.0 <= current.1 {
-            current.1 = range.1;
-        } else {
-            combined.push(current);
-            current = range;
-        }
-    }
-    combined.push(current);
+    find_combined_coverage(sensors, row)
+        .difference(&beacons)
+        .len()
+}
+
+/// The tuning frequency of the only spot in the square up to `max` no sensor covers.
+pub fn tuning_frequency(sensors: &[Sensor], max: i32) -> Option<u64> {
+    let bounds = IntervalSet::from(Interval::inclusive(0, max));
 
-    combined
+    (0..=max).find_map(|row| {
+        let gaps = bounds.difference(&find_combined_coverage(sensors, row));
+        gaps.min().map(|x| x as u64 * 4000000 + row as u64)
+    })
+}
+
+fn find_combined_coverage(sensors: &[Sensor], row: i32) -> IntervalSet<i32> {
+    sensors
+        .iter()
+        .filter_map(|sensor| sensor.find_row_coverage(row))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_search_example_area() {
+        let sensors = Day15::parse(include_str!("../fixtures/day15.txt")).unwrap();
+
+        assert_eq!(count_without_beacon(&sensors, 10), 26);
+        assert_eq!(tuning_frequency(&sensors, 20), Some(56000011));
+    }
 }
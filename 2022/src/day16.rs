@@ -1,64 +1,69 @@
-use std::collections::{BTreeSet, HashMap, HashSet, LinkedList};
+use std::collections::{BTreeSet, HashMap, HashSet};
 
-fn parse_input(input: &str) -> (HashMap<String, Vec<(String, u16)>>, HashMap<String, u16>) {
-    let graph = parse_graph(input);
-    let rates = parse_rates(input);
+use aoc_core::{search, Answer, Solution, SolveError};
 
-    (find_shortest_routes(graph, &rates), rates)
-}
+type ValveGraph = HashMap<String, Vec<(String, u16)>>;
+type Rates = HashMap<String, u16>;
 
-pub fn part1(input: &str) -> u16 {
-    let (valve_graph, rates) = parse_input(input);
-
-    let mut max_released = 0;
-    let mut opened = BTreeSet::new();
-    let mut all = HashMap::new();
-    dfs(
-        &valve_graph,
-        &rates,
-        "START",
-        30,
-        0,
-        &mut opened,
-        &mut max_released,
-        &mut all,
-    );
+pub struct Day16;
 
-    max_released
-}
+impl Solution for Day16 {
+    type Input = (ValveGraph, Rates);
 
-pub fn part2(input: &str) -> u16 {
-    let (valve_graph, rates) = parse_input(input);
-
-    let mut max_released = 0;
-    let mut opened = BTreeSet::new();
-    let mut all = HashMap::new();
-    dfs(
-        &valve_graph,
-        &rates,
-        "START",
-        26,
-        0,
-        &mut opened,
-        &mut max_released,
-        &mut all,
-    );
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        let graph = parse_graph(input);
+        let rates = parse_rates(input);
+
+        Ok((find_shortest_routes(graph, &rates), rates))
+    }
+
+    fn part1(valves: &Self::Input) -> Result<Answer, SolveError> {
+        let mut max_released = 0;
+        let mut opened = BTreeSet::new();
+        let mut all = HashMap::new();
+        dfs(
+            valves,
+            "START",
+            30,
+            0,
+            &mut opened,
+            &mut max_released,
+            &mut all,
+        );
+
+        Ok(max_released.into())
+    }
+
+    fn part2(valves: &Self::Input) -> Result<Answer, SolveError> {
+        let mut max_released = 0;
+        let mut opened = BTreeSet::new();
+        let mut all = HashMap::new();
+        dfs(
+            valves,
+            "START",
+            26,
+            0,
+            &mut opened,
+            &mut max_released,
+            &mut all,
+        );
 
-    let mut all: Vec<(String, u16)> = all.into_iter().collect();
-    all.sort_by(|(_, a), (_, b)| b.cmp(a));
+        let mut all: Vec<(String, u16)> = all.into_iter().collect();
+        all.sort_by(|(_, a), (_, b)| b.cmp(a));
 
-    for (i, (hash, released)) in all.iter().enumerate() {
-        let set: HashSet<&str> = HashSet::from_iter(hash.split(':'));
+        for (i, (hash, released)) in all.iter().enumerate() {
+            let set: HashSet<&str> = HashSet::from_iter(hash.split(':'));
 
-        for (hash_ele, released_ele) in all.iter().skip(i + 1) {
-            if hash_ele.split(':').all(|valve| !set.contains(valve)) {
-                max_released = max_released.max(released + released_ele);
-                break;
+            for (hash_ele, released_ele) in all.iter().skip(i + 1) {
+                if hash_ele.split(':').all(|valve| !set.contains(valve)) {
+                    max_released = max_released.max(released + released_ele);
+                    break;
+                }
             }
         }
-    }
 
-    max_released
+        Ok(max_released.into())
+    }
 }
 
 fn parse_graph(input: &str) -> HashMap<String, Vec<String>> {
@@ -72,10 +77,7 @@ fn parse_graph(input: &str) -> HashMap<String, Vec<String>> {
             let name = &first[6..8];
             let start = if second.contains("valves") { 23 } else { 22 };
 
-            let valves: Vec<String> = second[start..]
-                .split(", ")
-                .map(|p| String::from(p))
-                .collect();
+            let valves: Vec<String> = second[start..].split(", ").map(String::from).collect();
 
             (name, valves)
         })
@@ -89,7 +91,7 @@ fn parse_rates(input: &str) -> HashMap<String, u16> {
     input
         .lines()
         .map(|line| {
-            let first = line.split(";").next().unwrap();
+            let first = line.split(';').next().unwrap();
             let name = &first[6..8];
             let rate: u16 = first[23..].parse().unwrap();
 
@@ -102,67 +104,39 @@ fn parse_rates(input: &str) -> HashMap<String, u16> {
         })
 }
 
-fn find_shortest_routes(
-    graph: HashMap<String, Vec<String>>,
-    rates: &HashMap<String, u16>,
-) -> HashMap<String, Vec<(String, u16)>> {
-    let mut cache = HashMap::new();
-
-    let mut bfs = |start: &str, end: &str| -> u16 {
-        if let Some(cached) = cache.get(&(String::from(start), String::from(end))) {
-            return *cached;
-        }
-
-        let mut queue = LinkedList::from([(start, 0)]);
-        let mut visited = HashSet::from([start]);
-
-        while let Some((current, steps)) = queue.pop_front() {
-            if current == end {
-                cache.insert((String::from(start), String::from(end)), steps);
-                cache.insert((String::from(end), String::from(start)), steps);
-                return steps;
-            }
-
-            let unvisited: Vec<&str> = graph
-                .get(current)
-                .unwrap()
-                .iter()
-                .map(|next| next.as_str())
-                .filter(|next| !visited.contains(next))
-                .collect();
-
-            unvisited.iter().for_each(|next| {
-                visited.insert(next);
-                queue.push_back((next, steps + 1));
-            })
-        }
-
-        unreachable!()
-    };
+fn distances<'a>(
+    graph: &'a HashMap<String, Vec<String>>,
+    start: &'a str,
+) -> HashMap<&'a str, usize> {
+    search::bfs(
+        [start],
+        |current| graph[*current].iter().map(|next| next.as_str()),
+        |_| false,
+    )
+    .into_distances()
+}
 
-    let names: Vec<&str> = rates.iter().map(|(name, _)| name.as_str()).collect();
+fn find_shortest_routes(graph: HashMap<String, Vec<String>>, rates: &Rates) -> ValveGraph {
+    let names: Vec<&str> = rates.keys().map(|name| name.as_str()).collect();
     let mut valve_graph = HashMap::new();
 
     for start in names.iter() {
-        let mut ends = Vec::new();
-
-        for end in names.iter() {
-            if start == end {
-                continue;
-            }
-
-            let distance = bfs(start, end);
-            ends.push((String::from(*end), distance))
-        }
+        let from_start = distances(&graph, start);
+        let ends = names
+            .iter()
+            .filter(|end| *end != start)
+            .map(|end| (String::from(*end), from_start[end] as u16))
+            .collect();
 
         valve_graph.insert(String::from(*start), ends);
     }
 
+    let from_aa = distances(&graph, "AA");
     valve_graph.insert(
         String::from("START"),
         names
             .into_iter()
-            .map(|end| (String::from(end), bfs("AA", end)))
+            .map(|end| (String::from(end), from_aa[end] as u16))
             .collect(),
     );
 
@@ -170,8 +144,7 @@ fn find_shortest_routes(
 }
 
 fn dfs(
-    graph: &HashMap<String, Vec<(String, u16)>>,
-    rates: &HashMap<String, u16>,
+    valves: &(ValveGraph, Rates),
     current: &str,
     time_left: u16,
     mut released: u16,
@@ -179,6 +152,8 @@ fn dfs(
     max_released: &mut u16,
     all_maxes: &mut HashMap<String, u16>,
 ) {
+    let (graph, rates) = valves;
+
     if current != "START" {
         released += time_left * rates.get(current).unwrap();
     }
@@ -187,7 +162,7 @@ fn dfs(
         *max_released = released;
     }
 
-    if opened.len() > 0 {
+    if !opened.is_empty() {
         let mut iter = opened.iter();
         let hash = String::from(iter.next().unwrap());
         let hash = iter.fold(hash, |mut str, curr| {
@@ -222,9 +197,8 @@ fn dfs(
     nexts.into_iter().for_each(|(next, distance)| {
         opened.insert(next.clone());
         dfs(
-            graph,
-            rates,
-            &next,
+            valves,
+            next,
             time_left - distance - 1,
             released,
             opened,
@@ -1,6 +1,6 @@
 use std::fmt::Display;
 
-#[derive(Debug, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Rock {
     Horizontal,
     Cross,
@@ -60,7 +60,7 @@ impl Wind {
         }
     }
 
-    fn blow(&self, rock_coordinates: &mut Vec<(usize, usize)>) {
+    fn blow(&self, rock_coordinates: &mut [(usize, usize)]) {
         if rock_coordinates.iter().any(|(x, _)| *x == 0) && *self == Self::Left {
             return;
         }
@@ -79,12 +79,12 @@ impl Wind {
 }
 
 pub struct InfiniteStorm<'a> {
-    winds: &'a Vec<Wind>,
+    winds: &'a [Wind],
     next_index: usize,
 }
 
 impl<'a> InfiniteStorm<'a> {
-    pub fn new(winds: &'a Vec<Wind>) -> Self {
+    pub fn new(winds: &'a [Wind]) -> Self {
         InfiniteStorm {
             winds,
             next_index: 0,
@@ -103,7 +103,7 @@ impl<'a> Iterator for InfiniteStorm<'a> {
             }
             None => {
                 self.next_index = 1;
-                self.winds.get(0)
+                self.winds.first()
             }
         }
     }
@@ -165,7 +165,7 @@ impl<'a> Chamber<'a> {
         rock_coords
     }
 
-    fn is_blocked(&self, rock_coords: &Vec<(usize, usize)>) -> bool {
+    fn is_blocked(&self, rock_coords: &[(usize, usize)]) -> bool {
         rock_coords.iter().any(|(x, y)| self.chamber[*y][*x] == 1)
     }
 
@@ -177,7 +177,10 @@ impl<'a> Chamber<'a> {
     }
 
     pub fn get_top(&self) -> [u8; 7] {
-        self.chamber.get(self.top - 1).unwrap().clone()
+        match self.top {
+            0 => [0; 7],
+            top => self.chamber[top - 1],
+        }
     }
 
     pub fn get_wind_index(&self) -> usize {
@@ -256,9 +259,9 @@ mod tests {
 
     #[test]
     fn can_iterate_winds() {
-        let winds = vec!['>', '>', '<', '>', '<']
+        let winds: Vec<Wind> = vec!['>', '>', '<', '>', '<']
             .into_iter()
-            .map(|c| Wind::new(c))
+            .map(Wind::new)
             .collect();
         let mut storm = InfiniteStorm::new(&winds);
 
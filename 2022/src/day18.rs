@@ -1,60 +1,61 @@
 mod cube;
 
+use aoc_core::{Answer, Solution, SolveError};
+
 use self::cube::{Bounds, Cube, CubeGenerator, Side};
 use std::collections::{HashSet, LinkedList};
 
-fn parse_input(input: &str) -> Vec<Cube> {
-    input
-        .lines()
-        .map(|line| {
-            let parts: Vec<u8> = line.split(',').map(|part| part.parse().unwrap()).collect();
-            Cube::new(parts[0], parts[1], parts[2])
-        })
-        .collect()
-}
+pub struct Day18;
 
-pub fn part1(input: &str) -> usize {
-    let cubes = parse_input(input);
-    get_uncovered_sides(&cubes).len()
-}
+impl Solution for Day18 {
+    type Input = Vec<Cube>;
 
-pub fn part2(input: &str) -> usize {
-    let cubes = parse_input(input);
-
-    let all_uncovered = get_uncovered_sides(&cubes);
-    let bounds = cubes.iter().fold(
-        ((255, 0), (255, 0), (255, 0)),
-        |((x_min, x_max), (y_min, y_max), (z_min, z_max)), cube| {
-            let (x, y, z) = cube.coords();
-            (
-                (x_min.min(x), x_max.max(x)),
-                (y_min.min(y), y_max.max(y)),
-                (z_min.min(z), z_max.max(z)),
-            )
-        },
-    );
-
-    let mut visited_cubes = HashSet::new();
-    for cube in cubes {
-        visited_cubes.insert(cube);
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        Ok(input
+            .lines()
+            .map(|line| {
+                let parts: Vec<u8> = line.split(',').map(|part| part.parse().unwrap()).collect();
+                Cube::new(parts[0], parts[1], parts[2])
+            })
+            .collect())
     }
 
-    let mut inner_sides = HashSet::new();
-    let mut generator = CubeGenerator::new(bounds);
-    while let Some(cube) = generator.next() {
-        fill_cubes(
-            cube,
-            &mut visited_cubes,
-            &all_uncovered,
-            &mut inner_sides,
-            &bounds,
-        );
+    fn part1(cubes: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(get_uncovered_sides(cubes).len().into())
     }
 
-    all_uncovered.len() - inner_sides.len()
+    fn part2(cubes: &Self::Input) -> Result<Answer, SolveError> {
+        let all_uncovered = get_uncovered_sides(cubes);
+        let bounds = cubes.iter().fold(
+            ((255, 0), (255, 0), (255, 0)),
+            |((x_min, x_max), (y_min, y_max), (z_min, z_max)), cube| {
+                let (x, y, z) = cube.coords();
+                (
+                    (x_min.min(x), x_max.max(x)),
+                    (y_min.min(y), y_max.max(y)),
+                    (z_min.min(z), z_max.max(z)),
+                )
+            },
+        );
+
+        let mut visited_cubes: HashSet<Cube> = cubes.iter().copied().collect();
+
+        let mut inner_sides = HashSet::new();
+        for cube in CubeGenerator::new(bounds) {
+            fill_cubes(
+                cube,
+                &mut visited_cubes,
+                &all_uncovered,
+                &mut inner_sides,
+                &bounds,
+            );
+        }
+
+        Ok((all_uncovered.len() - inner_sides.len()).into())
+    }
 }
 
-fn get_uncovered_sides(cubes: &Vec<Cube>) -> HashSet<Side> {
+fn get_uncovered_sides(cubes: &[Cube]) -> HashSet<Side> {
     let mut uncovered = HashSet::new();
 
     cubes.iter().for_each(|cube| {
@@ -119,6 +120,6 @@ fn fill_cubes(
     }
 
     if inner {
-        inner_uncovered.extend(sides.into_iter());
+        inner_uncovered.extend(sides);
     }
 }
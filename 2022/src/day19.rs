@@ -1,39 +1,42 @@
 mod simulation;
 
+use aoc_core::{Answer, Solution, SolveError};
+
 use self::simulation::{Robot, Simulation};
 
-fn parse_input(input: &str) -> Vec<[Robot; 4]> {
-    input
-        .lines()
-        .map(|line| Robot::parse_blueprint(line))
-        .collect()
-}
+pub struct Day19;
 
-pub fn part1(input: &str) -> usize {
-    let blueprints = parse_input(input);
+impl Solution for Day19 {
+    type Input = Vec<[Robot; 4]>;
 
-    blueprints
-        .iter()
-        .enumerate()
-        .map(|(i, bp)| {
-            let mut simulation = Simulation::new(*bp, 24);
-            let max = simulation.run();
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        Ok(input.lines().map(Robot::parse_blueprint).collect())
+    }
 
-            (i + 1) * max as usize
-        })
-        .fold(0, |sum, max| sum + max)
-}
+    fn part1(blueprints: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(blueprints
+            .iter()
+            .enumerate()
+            .map(|(i, bp)| {
+                let mut simulation = Simulation::new(*bp, 24);
+                let max = simulation.run();
+
+                (i + 1) * max as usize
+            })
+            .sum::<usize>()
+            .into())
+    }
 
-pub fn part2(input: &str) -> u16 {
-    let blueprints = parse_input(input);
-
-    blueprints
-        .iter()
-        .take(3)
-        .map(|bp| {
-            let mut simulation = Simulation::new(*bp, 32);
-            simulation.run()
-        })
-        .reduce(|prod, max| prod * max)
-        .unwrap()
+    fn part2(blueprints: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(blueprints
+            .iter()
+            .take(3)
+            .map(|bp| {
+                let mut simulation = Simulation::new(*bp, 32);
+                simulation.run()
+            })
+            .reduce(|prod, max| prod * max)
+            .unwrap()
+            .into())
+    }
 }
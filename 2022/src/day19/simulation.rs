@@ -130,13 +130,13 @@ impl Action {
 
 impl PartialOrd for Action {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.get_prio().cmp(&other.get_prio()))
+        Some(self.cmp(other))
     }
 }
 
 impl Ord for Action {
     fn cmp(&self, other: &Self) -> Ordering {
-        self.partial_cmp(other).unwrap()
+        self.get_prio().cmp(&other.get_prio())
     }
 }
 
@@ -233,7 +233,7 @@ impl Cache {
             index.insert(*keys.0, (HashMap::new(), HashMap::new()));
         }
 
-        let (map_0, map_1) = index.get_mut(&keys.0).unwrap();
+        let (map_0, map_1) = index.get_mut(keys.0).unwrap();
         if !map_0.contains_key(keys.1) {
             map_0.insert(*keys.1, Vec::new());
         }
@@ -242,20 +242,20 @@ impl Cache {
             map_1.insert(*keys.2, Vec::new());
         }
 
-        map_0.get_mut(&keys.1).unwrap().push(Rc::clone(state_rc));
-        map_1.get_mut(&keys.2).unwrap().push(Rc::clone(state_rc));
+        map_0.get_mut(keys.1).unwrap().push(Rc::clone(state_rc));
+        map_1.get_mut(keys.2).unwrap().push(Rc::clone(state_rc));
     }
 
     fn check_indexes(&self, state: &State) -> bool {
-        if Self::check_index(&self.time_index, (&state.0, &state.1, &state.2), &state) {
+        if Self::check_index(&self.time_index, (&state.0, &state.1, &state.2), state) {
             return true;
         }
 
-        if Self::check_index(&self.resource_index, (&state.1, &state.0, &state.2), &state) {
+        if Self::check_index(&self.resource_index, (&state.1, &state.0, &state.2), state) {
             return true;
         }
 
-        Self::check_index(&self.robot_index, (&state.2, &state.0, &state.1), &state)
+        Self::check_index(&self.robot_index, (&state.2, &state.0, &state.1), state)
     }
 
     fn save_state(&mut self, state: State) {
@@ -360,7 +360,7 @@ impl Simulation {
             actions.remove(&Action::BuildRobot(self.blueprint[0], 2));
         }
 
-        if actions.len() == 0 {
+        if actions.is_empty() {
             return vec![Action::Wait(0)];
         }
 
@@ -421,7 +421,7 @@ impl Simulation {
 
     pub fn run(&mut self) -> u16 {
         let mut resources = [0, 0, 0, 0];
-        let mut robot_counts = self.initial_robot_counts.clone();
+        let mut robot_counts = self.initial_robot_counts;
 
         self.run_step(self.time_limit, &mut resources, &mut robot_counts);
 
@@ -450,13 +450,13 @@ mod tests {
     #[test]
     fn cache_works() {
         let mut cache = Cache::new((24, [1, 0, 0, 0], [1, 0, 0, 0]));
-        assert_eq!(cache.has_better((24, [1, 0, 0, 0], [1, 0, 0, 0])), true);
-        assert_eq!(cache.has_better((23, [1, 0, 0, 0], [1, 0, 0, 0])), true);
-        assert_eq!(cache.has_better((24, [0, 0, 0, 0], [1, 0, 0, 0])), true);
-        assert_eq!(cache.has_better((24, [1, 0, 0, 0], [0, 0, 0, 0])), true);
-        assert_eq!(cache.has_better((24, [1, 1, 0, 0], [1, 0, 0, 0])), false);
-        assert_eq!(cache.has_better((24, [1, 1, 0, 0], [1, 0, 0, 0])), true);
-        assert_eq!(cache.has_better((24, [0, 1, 0, 0], [1, 0, 0, 0])), true);
-        assert_eq!(cache.has_better((24, [0, 1, 1, 0], [1, 0, 0, 0])), false);
+        assert!(cache.has_better((24, [1, 0, 0, 0], [1, 0, 0, 0])));
+        assert!(cache.has_better((23, [1, 0, 0, 0], [1, 0, 0, 0])));
+        assert!(cache.has_better((24, [0, 0, 0, 0], [1, 0, 0, 0])));
+        assert!(cache.has_better((24, [1, 0, 0, 0], [0, 0, 0, 0])));
+        assert!(!cache.has_better((24, [1, 1, 0, 0], [1, 0, 0, 0])));
+        assert!(cache.has_better((24, [1, 1, 0, 0], [1, 0, 0, 0])));
+        assert!(cache.has_better((24, [0, 1, 0, 0], [1, 0, 0, 0])));
+        assert!(!cache.has_better((24, [0, 1, 1, 0], [1, 0, 0, 0])));
     }
 }
@@ -1,3 +1,5 @@
+use aoc_core::{Answer, Solution, SolveError};
+
 fn calc_move_score(their: char, mine: char) -> u32 {
     let score = mine as u32 - 'W' as u32;
     score
@@ -30,18 +32,33 @@ fn calc_move_score_correct(their: char, outcome: char) -> u32 {
     score + mine + 1
 }
 
-pub fn part1(input: &str) -> u32 {
-    input
-        .lines()
-        .map(|l| calc_move_score(l.chars().next().unwrap(), l.chars().nth(2).unwrap()))
-        .sum()
-}
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<(char, char)>;
+
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        Ok(input
+            .lines()
+            .map(|l| (l.chars().next().unwrap(), l.chars().nth(2).unwrap()))
+            .collect())
+    }
+
+    fn part1(rounds: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(rounds
+            .iter()
+            .map(|(their, mine)| calc_move_score(*their, *mine))
+            .sum::<u32>()
+            .into())
+    }
 
-pub fn part2(input: &str) -> u32 {
-    input
-        .lines()
-        .map(|l| calc_move_score_correct(l.chars().next().unwrap(), l.chars().nth(2).unwrap()))
-        .sum()
+    fn part2(rounds: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(rounds
+            .iter()
+            .map(|(their, outcome)| calc_move_score_correct(*their, *outcome))
+            .sum::<u32>()
+            .into())
+    }
 }
 
 #[cfg(test)]
@@ -1,35 +1,42 @@
 mod circle;
 
-use self::circle::CircularList;
+use aoc_core::{Answer, Solution, SolveError};
 
-pub fn part1(input: &str) -> i64 {
-    let nums = input.lines().map(|line| line.parse().unwrap()).collect();
+use self::circle::CircularList;
 
-    let circle = CircularList::from(&nums);
-    circle.move_all();
+pub struct Day20;
 
-    let coords: Vec<i64> = [1000, 2000, 3000]
-        .into_iter()
-        .map(|coord| circle.find_coord(coord))
-        .collect();
-    coords.into_iter().fold(0, |sum, val| sum + val)
-}
+impl Solution for Day20 {
+    type Input = Vec<i64>;
 
-pub fn part2(input: &str) -> i64 {
-    let nums = input
-        .lines()
-        .map(|line| line.parse::<i64>().unwrap() * 811589153)
-        .collect();
-
-    let circle = CircularList::from(&nums);
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        Ok(input.lines().map(|line| line.parse().unwrap()).collect())
+    }
 
-    for _ in 0..10 {
+    fn part1(nums: &Self::Input) -> Result<Answer, SolveError> {
+        let circle = CircularList::from(nums);
         circle.move_all();
+
+        Ok([1000, 2000, 3000]
+            .into_iter()
+            .map(|coord| circle.find_coord(coord))
+            .sum::<i64>()
+            .into())
     }
 
-    let coords: Vec<i64> = [1000, 2000, 3000]
-        .into_iter()
-        .map(|coord| circle.find_coord(coord))
-        .collect();
-    coords.into_iter().fold(0, |sum, val| sum + val)
+    fn part2(nums: &Self::Input) -> Result<Answer, SolveError> {
+        let nums: Vec<i64> = nums.iter().map(|num| num * 811589153).collect();
+
+        let circle = CircularList::from(&nums);
+
+        for _ in 0..10 {
+            circle.move_all();
+        }
+
+        Ok([1000, 2000, 3000]
+            .into_iter()
+            .map(|coord| circle.find_coord(coord))
+            .sum::<i64>()
+            .into())
+    }
 }
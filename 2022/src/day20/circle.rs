@@ -26,8 +26,8 @@ pub struct CircularList {
 }
 
 impl CircularList {
-    pub fn from(values: &Vec<i64>) -> Self {
-        assert!(values.len() > 0);
+    pub fn from(values: &[i64]) -> Self {
+        assert!(!values.is_empty());
 
         let original_order: Vec<Rc<RefCell<Node>>> = values
             .iter()
@@ -40,7 +40,7 @@ impl CircularList {
         let mut zero = Rc::clone(&first);
 
         let mut prev = Rc::clone(&first);
-        while let Some(node) = iter.next() {
+        for node in iter {
             let current = Rc::clone(node);
             current.borrow_mut().prev = Rc::downgrade(&prev);
             prev.borrow_mut().next = Rc::downgrade(&current);
@@ -102,7 +102,7 @@ impl CircularList {
 
     fn move_node(&self, node: Rc<RefCell<Node>>) {
         let val = node.borrow().value;
-        let steps = val.abs() as usize % (self.size - 1);
+        let steps = val.unsigned_abs() as usize % (self.size - 1);
         if steps == 0 {
             return;
         }
@@ -116,12 +116,12 @@ impl CircularList {
 
     pub fn move_all(&self) {
         for node in self.original_order.iter() {
-            self.move_node(Rc::clone(&node));
+            self.move_node(Rc::clone(node));
         }
     }
 
     pub fn find_coord(&self, coord: usize) -> i64 {
-        let steps = coord % (self.size - 1);
+        let steps = coord % self.size;
         let mut current = Rc::clone(&self.zero);
         for _ in 0..steps {
             let next = current.borrow().next.upgrade().unwrap();
@@ -132,3 +132,18 @@ impl CircularList {
         val
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_find_coords_past_the_end() {
+        // coordinates wrap around every element, moving a node is what skips the node itself
+        let list = CircularList::from(&[4, 0, 7]);
+
+        assert_eq!(list.find_coord(1), 7);
+        assert_eq!(list.find_coord(3), 0);
+        assert_eq!(list.find_coord(1000), 7);
+    }
+}
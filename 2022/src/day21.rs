@@ -1,21 +1,41 @@
 mod monkeymap;
 
-use self::monkeymap::{Equation, MonkeyMap};
+use std::collections::HashMap;
 
-pub fn part1(input: &str) -> i64 {
-    let monkeys = MonkeyMap::parse(input);
-    monkeys.get_val("root").unwrap()
-}
+use aoc_core::{expr::Expr, Answer, Solution, SolveError};
+
+use self::monkeymap::MonkeyMap;
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Input = MonkeyMap;
+
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        Ok(MonkeyMap::parse(input))
+    }
+
+    fn part1(monkeys: &Self::Input) -> Result<Answer, SolveError> {
+        let root = monkeys.expr("root", None).eval(&HashMap::new())?;
+        let root = root
+            .to_integer()
+            .ok_or_else(|| SolveError::Failed(format!("root would have to yell {root}")))?;
+
+        Ok((root as i64).into())
+    }
 
-pub fn part2(input: &str) -> i64 {
-    let mut monkeys = MonkeyMap::parse(input);
-    monkeys.correct();
-    let (unknown, val) = monkeys.calc_half();
+    fn part2(monkeys: &Self::Input) -> Result<Answer, SolveError> {
+        let (lhs, rhs) = monkeys.sides("root");
+        let (lhs, rhs) = (
+            monkeys.expr(lhs, Some("humn")),
+            monkeys.expr(rhs, Some("humn")),
+        );
 
-    let eq = monkeys
-        .build_humn_equation(unknown.as_str())
-        .substitute_var(unknown.as_str(), &Equation::Num(val))
-        .unwrap();
+        let humn = Expr::solve(&lhs, &rhs, "humn")?;
+        let humn = humn
+            .to_integer()
+            .ok_or_else(|| SolveError::Failed(format!("humn would have to yell {humn}")))?;
 
-    eq.calc()
+        Ok((humn as i64).into())
+    }
 }
@@ -264,12 +264,13 @@ impl CubeNet {
         }
 
         Self {
-            faces: faces.into_iter().map(|(_, face)| (face.id, face)).collect(),
+            faces: faces.into_values().map(|face| (face.id, face)).collect(),
             foldable_edges,
             folded: false,
         }
     }
 
+    #[allow(clippy::mutable_key_type)]
     fn translate(&mut self, d: (f64, f64, f64)) {
         let mut moved = HashSet::new();
         self.faces.iter().for_each(|(_, face)| {
@@ -282,6 +283,7 @@ impl CubeNet {
         });
     }
 
+    #[allow(clippy::mutable_key_type)]
     fn rotate(&mut self, faces: Vec<u8>, axis: Axis) {
         let mut rotated = HashSet::new();
         faces.into_iter().for_each(|face_id| {
@@ -325,8 +327,8 @@ impl CubeNet {
         };
 
         self.faces
-            .iter()
-            .map(|(_, face)| face.point_vals().into_iter().reduce(find_lesser).unwrap())
+            .values()
+            .map(|face| face.point_vals().into_iter().reduce(find_lesser).unwrap())
             .reduce(find_lesser)
             .unwrap()
             .coords()
@@ -397,7 +399,7 @@ impl CubeNet {
 
         let mut res = [[(0, 0, false); 4]; 6]; // [east, south, west, north]
 
-        for face_id in 0..6 {
+        for (face_id, edges) in res.iter_mut().enumerate() {
             let face = &self.faces[&(face_id as u8)];
             let plane = face.plane;
             let mut dir = face.right;
@@ -408,7 +410,7 @@ impl CubeNet {
                 0,
             ));
 
-            for dir_id in 0..4 {
+            for (dir_id, edge) in edges.iter_mut().enumerate() {
                 let neighbor = self.find_face_by_plane(dir);
 
                 let dir_on_neighbor = Axis::from_vec(Quaternion::rotate_point(
@@ -420,7 +422,7 @@ impl CubeNet {
                 let new_dir_id = neighbor.count_turns(dir_on_neighbor);
                 let new_dir_inverse = (new_dir_id + 2) % 4;
 
-                res[face_id][dir_id] = (
+                *edge = (
                     neighbor.id as usize,
                     new_dir_id as usize,
                     face.edge_axis(dir_id as u8) == neighbor.edge_axis(new_dir_inverse),
@@ -560,6 +562,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::mutable_key_type)]
     fn can_hash_pointpointer() {
         let p1_0 = PointPointer(Rc::new(RefCell::new(Point::new(50.0, 0.0, 0.0))));
         let p1_1 = PointPointer(Rc::clone(&p1_0.0));
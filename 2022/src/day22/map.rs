@@ -1,3 +1,7 @@
+use std::fmt::Display;
+
+use aoc_core::Direction;
+
 use super::cube::CubeNet;
 
 #[derive(Clone, Debug)]
@@ -18,58 +22,18 @@ impl Tile {
     }
 }
 
-#[derive(Clone, Copy, Debug)]
-pub enum Direction {
-    Right,
-    Down,
-    Left,
-    Up,
-}
-
-impl Direction {
-    fn new(val: usize) -> Self {
-        match val {
-            0 => Self::Right,
-            1 => Self::Down,
-            2 => Self::Left,
-            3 => Self::Up,
-            _ => unimplemented!(),
-        }
-    }
-
-    fn delta(&self) -> (isize, isize) {
-        match self {
-            Self::Right => (0, 1),
-            Self::Down => (1, 0),
-            Self::Left => (0, -1),
-            Self::Up => (-1, 0),
-        }
-    }
-
-    pub fn val(&self) -> usize {
-        match self {
-            Self::Right => 0,
-            Self::Down => 1,
-            Self::Left => 2,
-            Self::Up => 3,
-        }
+/// The puzzle scores facings clockwise from east, the cube net numbers its edges the same way.
+pub fn facing(dir: Direction) -> usize {
+    match dir {
+        Direction::E => 0,
+        Direction::S => 1,
+        Direction::W => 2,
+        Direction::N => 3,
     }
+}
 
-    fn turn(&self, turn: &Turn) -> Self {
-        match (self, turn) {
-            (Self::Right, Turn::Left) => Self::Up,
-            (Self::Down, Turn::Left) => Self::Right,
-            (Self::Left, Turn::Left) => Self::Down,
-            (Self::Up, Turn::Left) => Self::Left,
-
-            (Self::Right, Turn::Right) => Self::Down,
-            (Self::Down, Turn::Right) => Self::Left,
-            (Self::Left, Turn::Right) => Self::Up,
-            (Self::Up, Turn::Right) => Self::Right,
-
-            _ => self.clone(),
-        }
-    }
+fn from_facing(val: usize) -> Direction {
+    [Direction::E, Direction::S, Direction::W, Direction::N][val]
 }
 
 #[derive(Debug)]
@@ -88,6 +52,14 @@ impl Turn {
             _ => None,
         }
     }
+
+    fn apply(&self, dir: Direction) -> Direction {
+        match self {
+            Self::Left => dir.turn_left(),
+            Self::Right => dir.turn_right(),
+            Self::Stay => dir,
+        }
+    }
 }
 
 pub type Instruction = (u8, Turn);
@@ -155,11 +127,25 @@ impl Map {
             current = next;
         }
 
-        (current.0, current.1, start.2.turn(&inst.1))
+        (current.0, current.1, inst.1.apply(start.2))
     }
 
     pub fn find_start(&self) -> Position {
-        (0, self.col(0, 0, 1), Direction::Right)
+        (0, self.col(0, 0, 1), Direction::E)
+    }
+
+    /// The six faces cover the whole map, so their side follows from the number of tiles.
+    pub fn side_len(&self) -> usize {
+        let tiles = self
+            .0
+            .iter()
+            .flatten()
+            .filter(|tile| !matches!(tile, Tile::Void))
+            .count();
+        let side_len = ((tiles / 6) as f64).sqrt().round() as usize;
+        assert_eq!(6 * side_len * side_len, tiles, "map is not a cube net");
+
+        side_len
     }
 
     fn faces(&self, side_len: usize) -> (Vec<Vec<i8>>, [(usize, usize); 6]) {
@@ -186,15 +172,20 @@ impl Map {
     }
 }
 
+/// Where leaving each face in each facing leads: the face entered, the facing it's entered with
+/// and whether the offset along the edge is kept or mirrored.
+pub type EdgeMap = [[(usize, usize, bool); 4]; 6];
+
 pub struct CubeMap {
     map: Vec<Vec<Tile>>,
     face_corners: [(usize, usize); 6],
-    edge_map: [[(usize, usize, bool); 4]; 6],
+    edge_map: EdgeMap,
     side_len: usize,
 }
 
 impl CubeMap {
-    pub fn from_map(map: &Map, side_len: usize) -> Self {
+    pub fn from_map(map: &Map) -> Self {
+        let side_len = map.side_len();
         let (grid, face_corners) = map.faces(side_len);
 
         let mut cube_net = CubeNet::from_grid(grid, side_len as f64);
@@ -209,6 +200,34 @@ impl CubeMap {
         }
     }
 
+    pub fn edge_map(&self) -> &EdgeMap {
+        &self.edge_map
+    }
+
+    /// Checks that every edge leads back the way it came and that each face borders four
+    /// different faces.
+    pub fn validate(&self) -> Result<(), String> {
+        for (face, edges) in self.edge_map.iter().enumerate() {
+            for (dir, (other, entered, same)) in edges.iter().enumerate() {
+                let back = self.edge_map[*other][(entered + 2) % 4];
+                if back != (face, (dir + 2) % 4, *same) {
+                    return Err(format!(
+                        "face {face} facing {dir} leads to face {other}, which leads back to {back:?}"
+                    ));
+                }
+            }
+
+            let mut neighbors: Vec<usize> = edges.iter().map(|edge| edge.0).collect();
+            neighbors.sort();
+            neighbors.dedup();
+            if neighbors.len() != 4 || neighbors.contains(&face) {
+                return Err(format!("face {face} borders faces {neighbors:?}"));
+            }
+        }
+
+        Ok(())
+    }
+
     fn in_face(&self, row: usize, col: usize, face: usize) -> bool {
         let (fr, fc) = self.face_corners[face];
         row >= fr && col >= fc && row < fr + self.side_len && col < fc + self.side_len
@@ -235,10 +254,10 @@ impl CubeMap {
         let (fr_max, fc_max) = (fr + self.side_len - 1, fc + self.side_len - 1);
 
         match (dir, same_orientation) {
-            (Direction::Right | Direction::Left, true) => row - fr,
-            (Direction::Right | Direction::Left, false) => fr_max - row,
-            (Direction::Down | Direction::Up, true) => col - fc,
-            (Direction::Down | Direction::Up, false) => fc_max - col,
+            (Direction::E | Direction::W, true) => row - fr,
+            (Direction::E | Direction::W, false) => fr_max - row,
+            (Direction::S | Direction::N, true) => col - fc,
+            (Direction::S | Direction::N, false) => fc_max - col,
         }
     }
 
@@ -249,8 +268,8 @@ impl CubeMap {
         dir: Direction,
         face: usize,
     ) -> ((usize, usize), Direction, usize) {
-        let (new_face, new_dir, same_orientation) = self.edge_map[face][dir.val()];
-        let new_dir = Direction::new(new_dir);
+        let (new_face, new_dir, same_orientation) = self.edge_map[face][facing(dir)];
+        let new_dir = from_facing(new_dir);
         let (fr, fc) = self.face_corners[new_face];
         let (fr_max, fc_max) = (fr + self.side_len - 1, fc + self.side_len - 1);
 
@@ -258,10 +277,10 @@ impl CubeMap {
 
         (
             match new_dir {
-                Direction::Right => (fr + offset, fc),
-                Direction::Down => (fr, fc + offset),
-                Direction::Left => (fr + offset, fc_max),
-                Direction::Up => (fr_max, fc + offset),
+                Direction::E => (fr + offset, fc),
+                Direction::S => (fr, fc + offset),
+                Direction::W => (fr + offset, fc_max),
+                Direction::N => (fr_max, fc + offset),
             },
             new_dir,
             new_face,
@@ -296,6 +315,114 @@ impl CubeMap {
             face = next_face;
         }
 
-        (current.0, current.1, dir.turn(&inst.1))
+        (current.0, current.1, inst.1.apply(dir))
+    }
+}
+
+impl Display for CubeMap {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (face, edges) in self.edge_map.iter().enumerate() {
+            let (row, col) = self.face_corners[face];
+            writeln!(f, "face {face} at row {row}, col {col}:")?;
+
+            for (dir, (other, entered, same)) in edges.iter().enumerate() {
+                writeln!(
+                    f,
+                    "  {:?} -> face {other} heading {:?}{}",
+                    from_facing(dir),
+                    from_facing(*entered),
+                    if *same { "" } else { ", mirrored" }
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NETS: [&str; 11] = [
+        "#...\n####\n#...",
+        "#...\n####\n.#..",
+        "#...\n####\n..#.",
+        "#...\n####\n...#",
+        ".#..\n####\n.#..",
+        ".#..\n####\n..#.",
+        "##..\n.###\n.#..",
+        "##..\n.###\n..#.",
+        "##..\n.###\n...#",
+        "##..\n.##.\n..##",
+        "###..\n..###",
+    ];
+
+    /// Every rotation and mirror image of a net layout.
+    fn orientations(net: &str) -> Vec<Vec<Vec<bool>>> {
+        let mut layout: Vec<Vec<bool>> = net
+            .lines()
+            .map(|line| line.chars().map(|c| c == '#').collect())
+            .collect();
+
+        let mut orientations = Vec::new();
+        for _ in 0..4 {
+            let rotated = (0..layout[0].len())
+                .map(|c| layout.iter().rev().map(|row| row[c]).collect())
+                .collect();
+            layout = rotated;
+
+            orientations.push(layout.clone());
+            orientations.push(
+                layout
+                    .iter()
+                    .map(|row| row.iter().rev().copied().collect())
+                    .collect(),
+            );
+        }
+
+        orientations
+    }
+
+    fn build(layout: &[Vec<bool>], side_len: usize) -> Map {
+        Map::new(
+            layout
+                .iter()
+                .flat_map(|row| {
+                    let line: Vec<Tile> = row
+                        .iter()
+                        .flat_map(|face| match face {
+                            true => vec![Tile::Floor; side_len],
+                            false => vec![Tile::Void; side_len],
+                        })
+                        .collect();
+                    vec![line; side_len]
+                })
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn folds_every_net() {
+        let side_len = 3;
+
+        for layout in NETS.iter().flat_map(|net| orientations(net)) {
+            let map = build(&layout, side_len);
+            assert_eq!(map.side_len(), side_len);
+
+            let cube = CubeMap::from_map(&map);
+            assert_eq!(cube.validate(), Ok(()), "{layout:?}\n{cube}");
+
+            // going straight around a cube ends up where it started
+            for (row, col) in cube.face_corners {
+                for dir in Direction::ALL {
+                    for offset in 0..side_len {
+                        let start = (row + offset, col + offset, dir);
+                        let end = cube.make_move(start, &(4 * side_len as u8, Turn::Stay));
+                        assert_eq!(end, start, "{layout:?}\n{cube}");
+                    }
+                }
+            }
+        }
     }
 }
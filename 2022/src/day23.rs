@@ -1,24 +1,36 @@
 mod simulation;
 
+use aoc_core::{Answer, Solution, SolveError};
+
 use self::simulation::Simulation;
 
-pub fn part1(input: &str) -> usize {
-    let mut sim = Simulation::parse(input);
-    for _ in 0..10 {
-        sim.sim_round();
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Input = Simulation;
+
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        Ok(Simulation::parse(input))
     }
 
-    let (r_min, r_max, c_min, c_max) = sim.bounds();
+    fn part1(sim: &Self::Input) -> Result<Answer, SolveError> {
+        let mut sim = sim.clone();
+        for _ in 0..10 {
+            sim.sim_round();
+        }
 
-    (r_max - r_min + 1) as usize * (c_max - c_min + 1) as usize - sim.elf_count()
-}
+        let (r_min, r_max, c_min, c_max) = sim.bounds();
 
-pub fn part2(input: &str) -> usize {
-    let mut sim = Simulation::parse(input);
-    let mut i = 1;
-    while sim.sim_round() {
-        i += 1;
+        Ok(((r_max - r_min + 1) as usize * (c_max - c_min + 1) as usize - sim.elf_count()).into())
     }
 
-    i
+    fn part2(sim: &Self::Input) -> Result<Answer, SolveError> {
+        let mut sim = sim.clone();
+        let mut i = 1;
+        while sim.sim_round() {
+            i += 1;
+        }
+
+        Ok(i.into())
+    }
 }
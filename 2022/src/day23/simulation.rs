@@ -1,103 +1,42 @@
-use std::{
-    collections::{HashMap, HashSet, LinkedList},
-    ops::Add,
-};
+use std::collections::{HashMap, HashSet, LinkedList};
 
-#[derive(Debug, Clone, Copy)]
-enum Direction {
-    N,
-    S,
-    W,
-    E,
-    NE,
-    NW,
-    SE,
-    SW,
-}
-
-impl Direction {
-    fn val(&self) -> (isize, isize) {
-        let r = match self {
-            Self::N | Self::NW | Self::NE => -1,
-            Self::S | Self::SE | Self::SW => 1,
-            _ => 0,
-        };
-        let c = match self {
-            Self::W | Self::NW | Self::SW => -1,
-            Self::E | Self::NE | Self::SE => 1,
-            _ => 0,
-        };
-
-        (r, c)
-    }
-
-    fn adj(&self) -> (Self, Self) {
-        match self {
-            Self::N => (Self::NW, Self::NE),
-            Self::S => (Self::SW, Self::SE),
-            Self::W => (Self::SW, Self::NW),
-            Self::E => (Self::NE, Self::SE),
-            _ => unimplemented!(),
-        }
-    }
-}
-
-#[derive(Debug, Hash, PartialEq, Eq, Copy, Clone)]
-struct Elf(isize, isize);
+use aoc_core::{Compass, Grid, Point2};
 
-impl Elf {
-    fn propose(&self, other_elves: &HashSet<Elf>, order: &LinkedList<Direction>) -> Option<Elf> {
-        let valid: Vec<&Direction> = order
-            .into_iter()
-            .filter(|dir| {
-                let (left, right) = dir.adj();
-                let dirs = [dir, &left, &right];
-                dirs.iter().all(|d| {
-                    let (dr, dc) = d.val();
-                    !other_elves.contains(&(self + &Elf(dr, dc)))
-                })
-            })
-            .collect();
+type Elf = Point2<isize>;
 
-        if valid.len() == 4 || valid.len() == 0 {
-            return None;
-        }
+fn propose(elf: Elf, other_elves: &HashSet<Elf>, order: &LinkedList<Compass>) -> Option<Elf> {
+    let valid: Vec<&Compass> = order
+        .iter()
+        .filter(|dir| {
+            [dir.rotate_ccw(), **dir, dir.rotate_cw()]
+                .into_iter()
+                .all(|d| !other_elves.contains(&(elf + Point2::from(d))))
+        })
+        .collect();
 
-        let (dr, dc) = valid[0].val();
-        Some(self + &Elf(dr, dc))
+    if valid.len() == 4 || valid.is_empty() {
+        return None;
     }
-}
-
-impl Add for &Elf {
-    type Output = Elf;
 
-    fn add(self, rhs: Self) -> Self::Output {
-        Elf(self.0 + rhs.0, self.1 + rhs.1)
-    }
+    Some(elf + Point2::from(*valid[0]))
 }
 
+#[derive(Clone)]
 pub struct Simulation {
     elves: HashSet<Elf>,
-    order: LinkedList<Direction>,
+    order: LinkedList<Compass>,
 }
 
 impl Simulation {
     pub fn parse(input: &str) -> Self {
-        let elves = input
-            .lines()
-            .enumerate()
-            .flat_map(|(r, row)| {
-                row.chars()
-                    .enumerate()
-                    .filter_map(move |(c, char)| match char {
-                        '#' => Some(Elf(r as isize, c as isize)),
-                        _ => None,
-                    })
-            })
+        let elves = Grid::parse(input, |c| c == '#')
+            .iter()
+            .filter(|(_, elf)| **elf)
+            .map(|((r, c), _)| Elf::new(c as isize, r as isize))
             .collect();
         Self {
             elves,
-            order: LinkedList::from([Direction::N, Direction::S, Direction::W, Direction::E]),
+            order: LinkedList::from([Compass::N, Compass::S, Compass::W, Compass::E]),
         }
     }
 
@@ -105,7 +44,7 @@ impl Simulation {
         let mut proposals: HashMap<Elf, (Elf, bool)> = HashMap::new();
 
         self.elves.iter().for_each(|elf| {
-            if let Some(proposal) = elf.propose(&self.elves, &self.order) {
+            if let Some(proposal) = propose(*elf, &self.elves, &self.order) {
                 if let Some(curr) = proposals.get_mut(&proposal) {
                     curr.1 = false;
                 } else {
@@ -135,10 +74,10 @@ impl Simulation {
             (isize::MAX, isize::MIN, isize::MAX, isize::MIN),
             |bounds, elf| {
                 (
-                    bounds.0.min(elf.0),
-                    bounds.1.max(elf.0),
-                    bounds.2.min(elf.1),
-                    bounds.3.max(elf.1),
+                    bounds.0.min(elf.y),
+                    bounds.1.max(elf.y),
+                    bounds.2.min(elf.x),
+                    bounds.3.max(elf.x),
                 )
             },
         )
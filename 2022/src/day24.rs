@@ -1,23 +1,35 @@
 mod map;
 
+use aoc_core::{Answer, Solution, SolveError};
+
 use self::map::Map;
 
-pub fn part1(input: &str) -> usize {
-    let mut map = Map::parse(input);
+pub struct Day24;
 
-    map.traverse((0, 1), (map.height() - 1, map.width() - 2));
+impl Solution for Day24 {
+    type Input = Map;
 
-    map.steps_taken()
-}
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        Ok(Map::parse(input))
+    }
+
+    fn part1(map: &Self::Input) -> Result<Answer, SolveError> {
+        let mut map = map.clone();
+
+        map.traverse((0, 1), (map.height() - 1, map.width() - 2));
+
+        Ok(map.steps_taken().into())
+    }
 
-pub fn part2(input: &str) -> usize {
-    let mut map = Map::parse(input);
-    let start = (0, 1);
-    let end = (map.height() - 1, map.width() - 2);
+    fn part2(map: &Self::Input) -> Result<Answer, SolveError> {
+        let mut map = map.clone();
+        let start = (0, 1);
+        let end = (map.height() - 1, map.width() - 2);
 
-    map.traverse(start, end);
-    map.traverse(end, start);
-    map.traverse(start, end);
+        map.traverse(start, end);
+        map.traverse(end, start);
+        map.traverse(start, end);
 
-    map.steps_taken()
+        Ok(map.steps_taken().into())
+    }
 }
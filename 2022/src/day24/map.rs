@@ -1,53 +1,16 @@
-use std::collections::{HashMap, HashSet, LinkedList};
+use std::collections::HashMap;
 
-#[derive(Debug, PartialEq)]
+use aoc_core::{math, search, Direction};
+
+#[derive(Debug, Clone, PartialEq)]
 enum Tile {
     Ground,
     BlizPortal(usize, usize),
 }
 
-#[derive(Debug, Clone)]
-enum Direction {
-    N,
-    S,
-    W,
-    E,
-}
-
-impl Direction {
-    fn parse(c: char) -> Self {
-        match c {
-            '>' => Direction::E,
-            '<' => Direction::W,
-            'v' => Direction::S,
-            '^' => Direction::N,
-            _ => unimplemented!(),
-        }
-    }
-
-    fn val(&self) -> (isize, isize) {
-        match self {
-            Self::E => (0, 1),
-            Self::W => (0, -1),
-            Self::S => (1, 0),
-            Self::N => (-1, 0),
-        }
-    }
-}
-
 type BlizzardMap = HashMap<(usize, usize), Vec<Direction>>;
 
-#[derive(PartialEq, Eq, Debug)]
-struct Node {
-    pos: (usize, usize),
-    f: usize,
-    g: usize,
-    h: usize,
-    steps: usize,
-    blizz_id: usize,
-}
-
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Map {
     map: Vec<Vec<Tile>>,
     blizzard_memo: HashMap<usize, BlizzardMap>,
@@ -87,13 +50,13 @@ impl Map {
                     .enumerate()
                     .filter_map(|(c, char)| match char {
                         '#' | '.' => None,
-                        char => Some(((r, c), vec![Direction::parse(char)])),
+                        char => Some(((r, c), vec![Direction::parse(char).unwrap()])),
                     })
                     .collect::<Vec<((usize, usize), Vec<Direction>)>>()
             })
             .collect();
 
-        let blizzard_loop = lcm(map.len() - 2, map[0].len() - 2);
+        let blizzard_loop = math::lcm(map.len() - 2, map[0].len() - 2);
 
         Self {
             map,
@@ -120,9 +83,9 @@ impl Map {
         let prev = self.blizzard_memo.get(&(id - 1)).unwrap();
         let mut new_bliz: BlizzardMap = HashMap::new();
 
-        prev.into_iter().for_each(|((r, c), dirs)| {
-            dirs.into_iter().for_each(|dir| {
-                let d = dir.val();
+        prev.iter().for_each(|((r, c), dirs)| {
+            dirs.iter().for_each(|dir| {
+                let d = dir.delta();
                 let nr = (*r as isize + d.0) as usize;
                 let nc = (*c as isize + d.1) as usize;
 
@@ -132,9 +95,9 @@ impl Map {
                 };
 
                 if let Some(v) = new_bliz.get_mut(&(nr, nc)) {
-                    v.push(dir.clone());
+                    v.push(*dir);
                 } else {
-                    new_bliz.insert((nr, nc), vec![dir.clone()]);
+                    new_bliz.insert((nr, nc), vec![*dir]);
                 }
             });
         });
@@ -151,57 +114,38 @@ impl Map {
     }
 
     fn next_tiles(&mut self, (r, c): (usize, usize), blizz_id: usize) -> Vec<(usize, usize)> {
-        [(r + 1, c), (r, c + 1), (r - 1, c), (r, c - 1), (r, c)]
-            .into_iter()
-            .filter(|coords| {
-                coords.0 < self.map.len()
-                    && coords.1 < self.map[0].len()
-                    && self.map[coords.0][coords.1] == Tile::Ground
-                    && !self.in_blizzard(coords, blizz_id)
-            })
-            .collect()
+        [
+            (r + 1, c),
+            (r, c + 1),
+            (r.wrapping_sub(1), c),
+            (r, c.wrapping_sub(1)),
+            (r, c),
+        ]
+        .into_iter()
+        .filter(|coords| {
+            coords.0 < self.map.len()
+                && coords.1 < self.map[0].len()
+                && self.map[coords.0][coords.1] == Tile::Ground
+                && !self.in_blizzard(coords, blizz_id)
+        })
+        .collect()
     }
 
     pub fn traverse(&mut self, from: (usize, usize), to: (usize, usize)) {
-        let mut states = HashSet::from([(from, self.blizzard_id)]);
-        let mut queue = LinkedList::from([(from, self.steps_taken, self.blizzard_id)]);
-
-        while let Some((current, steps, blizz_id)) = queue.pop_front() {
-            if current == to {
-                self.steps_taken = steps;
-                self.blizzard_id = blizz_id;
-
-                return;
-            }
-
-            let blizz_id = (blizz_id + 1) % self.blizzard_loop;
-            let steps = steps + 1;
-
-            let next = self.next_tiles(current, blizz_id);
-            for coords in next {
-                if !states.contains(&(coords, blizz_id)) {
-                    states.insert((coords, blizz_id));
-                    queue.push_back((coords, steps, blizz_id));
-                }
-            }
-        }
-
-        unreachable!()
+        let blizzard_loop = self.blizzard_loop;
+        let paths = search::bfs(
+            [(from, self.blizzard_id)],
+            |(current, blizz_id)| {
+                let blizz_id = (blizz_id + 1) % blizzard_loop;
+                self.next_tiles(*current, blizz_id)
+                    .into_iter()
+                    .map(move |coords| (coords, blizz_id))
+            },
+            |(current, _)| *current == to,
+        );
+
+        let (_, blizz_id) = *paths.goal().unwrap();
+        self.steps_taken += paths.goal_distance().unwrap();
+        self.blizzard_id = blizz_id;
     }
 }
-
-fn gcd(a: usize, b: usize) -> usize {
-    if a == b {
-        return a;
-    }
-
-    if a > b {
-        return gcd(a - b, b);
-    }
-
-    gcd(a, b - a)
-}
-
-fn lcm(a: usize, b: usize) -> usize {
-    a * b / gcd(a, b)
-}
@@ -1,13 +1,24 @@
 mod snafu;
 
-use self::snafu::Snafu;
+use aoc_core::{Answer, Solution, SolveError};
 
-pub fn part1(input: &str) -> Snafu {
-    let snafus: Vec<Snafu> = input.lines().map(|line| Snafu::parse(line)).collect();
-    snafus.into_iter().reduce(|sum, snafu| sum + snafu).unwrap()
-}
+pub use self::snafu::{Balanced, Digits, Five, Snafu, Three};
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Input = Vec<Snafu>;
+
+    const PARTS: u8 = 1;
+
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        Ok(input
+            .lines()
+            .map(|line| line.parse())
+            .collect::<Result<_, _>>()?)
+    }
 
-pub fn part2(_input: &str) -> u8 {
-    //stub
-    0
+    fn part1(snafus: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(snafus.iter().cloned().sum::<Snafu>().into())
+    }
 }
@@ -1,139 +1,343 @@
-use std::{collections::LinkedList, fmt::Display, ops::Add};
-
-#[derive(Clone, Copy, PartialEq)]
-enum Digit {
-    N2,
-    N1,
-    Z,
-    P1,
-    P2,
-}
-
-impl Digit {
-    fn new(c: char) -> Self {
-        match c {
-            '=' => Digit::N2,
-            '-' => Digit::N1,
-            '0' => Digit::Z,
-            '1' => Digit::P1,
-            '2' => Digit::P2,
-            _ => unimplemented!(),
-        }
-    }
+use std::{
+    cmp::Ordering,
+    fmt::{self, Display},
+    iter::Sum,
+    marker::PhantomData,
+    ops::{Add, Mul, Neg, Sub},
+    str::FromStr,
+};
+
+use aoc_core::Answer;
+
+/// The symbols of a balanced base, from the lowest digit to the highest. There's an odd number of
+/// them with zero in the middle.
+pub trait Digits {
+    const SYMBOLS: &'static [char];
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Five;
+
+impl Digits for Five {
+    const SYMBOLS: &'static [char] = &['=', '-', '0', '1', '2'];
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Three;
+
+impl Digits for Three {
+    const SYMBOLS: &'static [char] = &['-', '0', '+'];
 }
 
-impl Display for Digit {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+pub type Snafu = Balanced<Five>;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Error {
+    Empty,
+    InvalidDigit {
+        digit: char,
+        at: usize,
+    },
+    /// The number doesn't fit the integer type it's converted to.
+    TooLarge,
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            Digit::N2 => write!(f, "="),
-            Digit::N1 => write!(f, "-"),
-            Digit::Z => write!(f, "0"),
-            Digit::P1 => write!(f, "1"),
-            Digit::P2 => write!(f, "2"),
+            Self::Empty => write!(f, "no digits"),
+            Self::InvalidDigit { digit, at } => write!(f, "'{digit}' at {at} is not a digit"),
+            Self::TooLarge => write!(f, "number is too large"),
         }
     }
 }
 
-impl Add for Digit {
-    type Output = (Digit, Digit);
-
-    fn add(self, rhs: Self) -> Self::Output {
-        match (self, rhs) {
-            (Digit::Z, rhs) => (Digit::Z, rhs),
-            (lhs, Digit::Z) => (Digit::Z, lhs),
-            (Digit::P1, Digit::P1) => (Digit::Z, Digit::P2),
-            (Digit::P1, Digit::P2) | (Digit::P2, Digit::P1) => (Digit::P1, Digit::N2),
-            (Digit::P1, Digit::N1) | (Digit::N1, Digit::P1) => (Digit::Z, Digit::Z),
-            (Digit::P1, Digit::N2) | (Digit::N2, Digit::P1) => (Digit::Z, Digit::N1),
-            (Digit::P2, Digit::P2) => (Digit::P1, Digit::N1),
-            (Digit::P2, Digit::N1) | (Digit::N1, Digit::P2) => (Digit::Z, Digit::P1),
-            (Digit::P2, Digit::N2) | (Digit::N2, Digit::P2) => (Digit::Z, Digit::Z),
-            (Digit::N1, Digit::N1) => (Digit::Z, Digit::N2),
-            (Digit::N1, Digit::N2) | (Digit::N2, Digit::N1) => (Digit::N1, Digit::P2),
-            (Digit::N2, Digit::N2) => (Digit::N1, Digit::P1),
+impl std::error::Error for Error {}
+
+/// An integer of any size in a balanced base, where the digits go from `-base / 2` to
+/// `base / 2`. The digits are stored lowest first without leading zeros, so zero has none.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Balanced<D> {
+    digits: Vec<i8>,
+    base: PhantomData<D>,
+}
+
+impl<D: Digits> Balanced<D> {
+    const BASE: i64 = D::SYMBOLS.len() as i64;
+    const MAX_DIGIT: i64 = Self::BASE / 2;
+
+    pub fn zero() -> Self {
+        Self::normalize(Vec::new())
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.digits.is_empty()
+    }
+
+    /// Builds the number from digit values of any size, lowest first, by carrying into the
+    /// next digit until every one is in range.
+    fn normalize(values: Vec<i64>) -> Self {
+        let mut digits = Vec::with_capacity(values.len() + 1);
+        let mut carry = 0;
+        let mut values = values.into_iter();
+
+        loop {
+            let value = match values.next() {
+                Some(value) => value + carry,
+                None if carry != 0 => carry,
+                None => break,
+            };
+
+            let (mut digit, mut next) =
+                (value.rem_euclid(Self::BASE), value.div_euclid(Self::BASE));
+            if digit > Self::MAX_DIGIT {
+                digit -= Self::BASE;
+                next += 1;
+            }
+
+            digits.push(digit as i8);
+            carry = next;
+        }
+
+        while digits.last() == Some(&0) {
+            digits.pop();
+        }
+
+        Balanced {
+            digits,
+            base: PhantomData,
         }
     }
+
+    pub fn signum(&self) -> i8 {
+        self.digits.last().map_or(0, |digit| digit.signum())
+    }
+}
+
+macro_rules! impl_integer {
+    ($($t:ty),+) => {
+        $(
+          impl<D: Digits> From<$t> for Balanced<D> {
+              fn from(mut num: $t) -> Self {
+                  let (base, max) = (Balanced::<D>::BASE as $t, Balanced::<D>::MAX_DIGIT as $t);
+                  let mut digits = Vec::new();
+
+                  while num != 0 {
+                      let (mut digit, mut next) = (num.rem_euclid(base), num.div_euclid(base));
+                      if digit > max {
+                          digit -= base;
+                          next += 1;
+                      }
+
+                      digits.push(digit as i8);
+                      num = next;
+                  }
+
+                  Balanced {
+                      digits,
+                      base: PhantomData,
+                  }
+              }
+          }
+
+          impl<D: Digits> TryFrom<&Balanced<D>> for $t {
+              type Error = Error;
+
+              fn try_from(num: &Balanced<D>) -> Result<Self, Error> {
+                  let base = Balanced::<D>::BASE as $t;
+
+                  num.digits
+                      .iter()
+                      .rev()
+                      .try_fold(0 as $t, |value, digit| {
+                          // borrow from the digit when the two have opposite signs, so the
+                          // product never passes the result on its way there
+                          let (value, digit) = match (value.signum(), digit.signum()) {
+                              (-1, 1) => (value + 1, *digit as $t - base),
+                              (1, -1) => (value - 1, *digit as $t + base),
+                              _ => (value, *digit as $t),
+                          };
+                          value.checked_mul(base)?.checked_add(digit)
+                      })
+                      .ok_or(Error::TooLarge)
+              }
+          }
+        )+
+    };
 }
 
-pub struct Snafu(LinkedList<Digit>);
+impl_integer!(i64, i128);
 
-impl Snafu {
-    pub fn parse(input: &str) -> Self {
-        Self(input.chars().map(|c| Digit::new(c)).collect())
+impl<D: Digits> FromStr for Balanced<D> {
+    type Err = Error;
+
+    fn from_str(input: &str) -> Result<Self, Error> {
+        if input.is_empty() {
+            return Err(Error::Empty);
+        }
+
+        let values = input
+            .chars()
+            .enumerate()
+            .map(
+                |(at, digit)| match D::SYMBOLS.iter().position(|s| *s == digit) {
+                    Some(value) => Ok(value as i64 - Self::MAX_DIGIT),
+                    None => Err(Error::InvalidDigit { digit, at }),
+                },
+            )
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self::normalize(values.into_iter().rev().collect()))
     }
 }
 
-impl Display for Snafu {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        for digit in self.0.iter() {
-            write!(f, "{digit}")?
+impl<D: Digits> Display for Balanced<D> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_zero() {
+            return write!(f, "{}", D::SYMBOLS[Self::MAX_DIGIT as usize]);
+        }
+
+        for digit in self.digits.iter().rev() {
+            write!(
+                f,
+                "{}",
+                D::SYMBOLS[(*digit as i64 + Self::MAX_DIGIT) as usize]
+            )?;
         }
+
         Ok(())
     }
 }
 
-impl Add for Snafu {
-    type Output = Snafu;
+impl<D: Digits> From<Balanced<D>> for Answer {
+    fn from(num: Balanced<D>) -> Self {
+        Self::Text(num.to_string())
+    }
+}
+
+impl<D: Digits> Neg for Balanced<D> {
+    type Output = Self;
 
-    fn add(mut self, mut rhs: Self) -> Self::Output {
-        let mut sum = LinkedList::new();
-        let mut carry = LinkedList::new();
-        let mut s;
+    fn neg(mut self) -> Self {
+        self.digits.iter_mut().for_each(|digit| *digit = -*digit);
+        self
+    }
+}
 
-        let len = self.0.len().min(rhs.0.len());
+impl<D: Digits> Add for Balanced<D> {
+    type Output = Self;
 
-        let add = |a: Digit, b: Digit, mut carry: LinkedList<Digit>| {
-            let c_count = carry.len();
-            let (mut c, mut s) = a + b;
+    fn add(self, rhs: Self) -> Self {
+        let len = self.digits.len().max(rhs.digits.len());
+        let digit = |num: &Self, i: usize| num.digits.get(i).map_or(0, |digit| *digit as i64);
 
-            if c != Digit::Z {
-                carry.push_back(c);
-            }
+        Self::normalize((0..len).map(|i| digit(&self, i) + digit(&rhs, i)).collect())
+    }
+}
 
-            for _ in 0..c_count {
-                (c, s) = s + carry.pop_front().unwrap();
-                if c != Digit::Z {
-                    carry.push_back(c);
-                }
-            }
+impl<D: Digits> Sub for Balanced<D> {
+    type Output = Self;
 
-            (s, carry)
-        };
+    fn sub(self, rhs: Self) -> Self {
+        self + -rhs
+    }
+}
 
-        for _ in 0..len {
-            let a = self.0.pop_back().unwrap();
-            let b = rhs.0.pop_back().unwrap();
-            (s, carry) = add(a, b, carry);
+impl<D: Digits> Mul for Balanced<D> {
+    type Output = Self;
 
-            sum.push_front(s);
+    fn mul(self, rhs: Self) -> Self {
+        if self.is_zero() || rhs.is_zero() {
+            return Self::zero();
         }
 
-        while let Some(a) = carry.pop_front() {
-            let b = if self.0.len() > 0 {
-                self.0.pop_back().unwrap()
-            } else if rhs.0.len() > 0 {
-                rhs.0.pop_back().unwrap()
-            } else {
-                Digit::Z
-            };
-
-            (s, carry) = add(a, b, carry);
-            sum.push_front(s);
+        let mut values = vec![0; self.digits.len() + rhs.digits.len()];
+        for (i, a) in self.digits.iter().enumerate() {
+            for (j, b) in rhs.digits.iter().enumerate() {
+                values[i + j] += *a as i64 * *b as i64;
+            }
         }
 
-        let mut remaining = if self.0.len() > 0 {
-            self.0
-        } else if rhs.0.len() > 0 {
-            rhs.0
-        } else {
-            LinkedList::new()
+        Self::normalize(values)
+    }
+}
+
+impl<D: Digits> Sum for Balanced<D> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::zero(), Add::add)
+    }
+}
+
+impl<D: Digits + Eq> Ord for Balanced<D> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // a longer number is further from zero, and for the same length the highest digit that
+        // differs decides
+        match self.digits.len().cmp(&other.digits.len()) {
+            Ordering::Equal => self.digits.iter().rev().cmp(other.digits.iter().rev()),
+            Ordering::Greater => self.signum().cmp(&0),
+            Ordering::Less => 0.cmp(&other.signum()),
         }
-        .into_iter();
+    }
+}
+
+impl<D: Digits + Eq> PartialOrd for Balanced<D> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-        while let Some(rem) = remaining.next_back() {
-            sum.push_front(rem)
+    #[test]
+    fn converts_to_and_from_integers() {
+        for (text, num) in [
+            ("1=-0-2", 1747),
+            ("2=-01", 976),
+            ("1121-1110-1=0", 314159265),
+        ] {
+            let snafu: Snafu = text.parse().unwrap();
+            assert_eq!(i64::try_from(&snafu), Ok(num));
+            assert_eq!(Snafu::from(num).to_string(), text);
         }
 
-        Self(sum)
+        assert_eq!(Snafu::from(0_i64).to_string(), "0");
+        assert_eq!(Snafu::from(-8_i64).to_string(), "=2");
+        assert_eq!("002".parse::<Snafu>(), Ok(Snafu::from(2_i64)));
+        assert_eq!(Balanced::<Three>::from(-5_i64).to_string(), "-++");
+
+        let huge = Snafu::from(i128::MAX);
+        assert_eq!(i128::try_from(&huge), Ok(i128::MAX));
+        assert_eq!(i64::try_from(&huge), Err(Error::TooLarge));
+        assert_eq!(i128::try_from(&Snafu::from(i128::MIN)), Ok(i128::MIN));
+    }
+
+    #[test]
+    fn reports_bad_digits() {
+        assert_eq!("".parse::<Snafu>(), Err(Error::Empty));
+        assert_eq!(
+            "1=3".parse::<Snafu>(),
+            Err(Error::InvalidDigit { digit: '3', at: 2 })
+        );
+    }
+
+    #[test]
+    fn does_arithmetic() {
+        let (a, b) = (Snafu::from(1747_i64), Snafu::from(-976_i64));
+
+        assert_eq!(a.clone() + b.clone(), Snafu::from(771_i64));
+        assert_eq!(a.clone() - b.clone(), Snafu::from(2723_i64));
+        assert_eq!(a.clone() * b.clone(), Snafu::from(-1705072_i64));
+        assert_eq!(-a.clone(), Snafu::from(-1747_i64));
+        assert_eq!(a.clone() * Snafu::zero(), Snafu::zero());
+        assert!(b < Snafu::zero() && Snafu::zero() < a);
+        assert_eq!([a, b].into_iter().sum::<Snafu>(), Snafu::from(771_i64));
+
+        let big = Snafu::from(i64::MAX) * Snafu::from(i64::MAX);
+        assert_eq!(
+            i128::try_from(&big),
+            Ok(i64::MAX as i128 * i64::MAX as i128)
+        );
     }
 }
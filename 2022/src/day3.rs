@@ -1,5 +1,7 @@
 use std::collections::HashSet;
 
+use aoc_core::{Answer, Solution, SolveError};
+
 fn calc_priority(item: char) -> u32 {
     if item == item.to_ascii_lowercase() {
         item as u32 - 'a' as u32 + 1
@@ -8,46 +10,61 @@ fn calc_priority(item: char) -> u32 {
     }
 }
 
-pub fn part1(input: &str) -> u32 {
-    let rucksacks: Vec<(HashSet<char>, HashSet<char>)> = input
-        .lines()
-        .map(|l| {
-            let item_count = l.len();
-            let first_pocket: HashSet<char> = l[..item_count / 2].chars().collect();
-            let second_pocket: HashSet<char> = l[item_count / 2..].chars().collect();
-
-            (first_pocket, second_pocket)
-        })
-        .collect();
-
-    rucksacks
-        .iter()
-        .map(|(first_pocket, second_pocket)| {
-            let item = first_pocket
-                .iter()
-                .find(|item| second_pocket.contains(item))
-                .unwrap();
+pub struct Day3;
 
-            calc_priority(*item)
-        })
-        .sum()
-}
+impl Solution for Day3 {
+    type Input = Vec<Vec<char>>;
 
-pub fn part2(input: &str) -> u32 {
-    let rucksacks: Vec<HashSet<char>> = input.lines().map(|l| l.chars().collect()).collect();
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        Ok(input.lines().map(|l| l.chars().collect()).collect())
+    }
+
+    fn part1(rucksacks: &Self::Input) -> Result<Answer, SolveError> {
+        let rucksacks: Vec<(HashSet<char>, HashSet<char>)> = rucksacks
+            .iter()
+            .map(|items| {
+                let item_count = items.len();
+                let first_pocket: HashSet<char> = items[..item_count / 2].iter().copied().collect();
+                let second_pocket: HashSet<char> =
+                    items[item_count / 2..].iter().copied().collect();
 
-    let mut iter = rucksacks.iter();
-    let mut total = 0;
+                (first_pocket, second_pocket)
+            })
+            .collect();
 
-    while let Some(first_elf) = iter.next() {
-        let other_elves = (iter.next().unwrap(), iter.next().unwrap());
-        let item = first_elf
+        Ok(rucksacks
             .iter()
-            .find(|item| other_elves.0.contains(item) && other_elves.1.contains(item))
-            .unwrap();
+            .map(|(first_pocket, second_pocket)| {
+                let item = first_pocket
+                    .iter()
+                    .find(|item| second_pocket.contains(item))
+                    .unwrap();
 
-        total += calc_priority(*item);
+                calc_priority(*item)
+            })
+            .sum::<u32>()
+            .into())
     }
 
-    total
+    fn part2(rucksacks: &Self::Input) -> Result<Answer, SolveError> {
+        let rucksacks: Vec<HashSet<char>> = rucksacks
+            .iter()
+            .map(|items| items.iter().copied().collect())
+            .collect();
+
+        let mut iter = rucksacks.iter();
+        let mut total = 0;
+
+        while let Some(first_elf) = iter.next() {
+            let other_elves = (iter.next().unwrap(), iter.next().unwrap());
+            let item = first_elf
+                .iter()
+                .find(|item| other_elves.0.contains(item) && other_elves.1.contains(item))
+                .unwrap();
+
+            total += calc_priority(*item);
+        }
+
+        Ok(total.into())
+    }
 }
@@ -1,36 +1,43 @@
-mod range;
+use aoc_core::{Answer, Interval, Solution, SolveError};
 
-use self::range::Range;
-
-fn parse_input(input: &str) -> Vec<(Range, Range)> {
-    input
-        .lines()
-        .map(|l| {
-            let mut parts = l.split(',');
-            (
-                Range::parse(parts.next().unwrap()),
-                Range::parse(parts.next().unwrap()),
-            )
-        })
-        .collect()
+fn parse_range(range: &str) -> Interval<u8> {
+    let mut iter = range.split('-').map(|d| d.trim().parse().unwrap());
+    Interval::inclusive(iter.next().unwrap(), iter.next().unwrap())
 }
 
-pub fn part1(input: &str) -> usize {
-    let ranges = parse_input(input);
+pub struct Day4;
 
-    ranges
-        .iter()
-        .filter(|(first_range, second_range)| {
-            first_range.contains(second_range) || second_range.contains(first_range)
-        })
-        .count()
-}
+impl Solution for Day4 {
+    type Input = Vec<(Interval<u8>, Interval<u8>)>;
+
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        Ok(input
+            .lines()
+            .map(|l| {
+                let mut parts = l.split(',');
+                (
+                    parse_range(parts.next().unwrap()),
+                    parse_range(parts.next().unwrap()),
+                )
+            })
+            .collect())
+    }
 
-pub fn part2(input: &str) -> usize {
-    let ranges = parse_input(input);
+    fn part1(ranges: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(ranges
+            .iter()
+            .filter(|(first_range, second_range)| {
+                first_range.covers(second_range) || second_range.covers(first_range)
+            })
+            .count()
+            .into())
+    }
 
-    ranges
-        .iter()
-        .filter(|(first_range, second_range)| first_range.overlaps(second_range))
-        .count()
+    fn part2(ranges: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(ranges
+            .iter()
+            .filter(|(first_range, second_range)| first_range.overlaps(second_range))
+            .count()
+            .into())
+    }
 }
@@ -1,33 +1,32 @@
 mod stacks;
 
+use aoc_core::{Answer, Solution, SolveError};
+
 use self::stacks::{Move, Stacks};
 
-fn parse_input(input: &str) -> (Stacks, Vec<Move>) {
-    let mut parts = input.split("\n\n");
-    let stacks = Stacks::parse(parts.next().unwrap());
+pub struct Day5;
 
-    let moves = parts
-        .next()
-        .unwrap()
-        .lines()
-        .map(|line| Move::parse(line))
-        .collect();
+impl Solution for Day5 {
+    type Input = (Stacks, Vec<Move>);
 
-    (stacks, moves)
-}
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        let mut parts = input.split("\n\n");
+        let stacks = Stacks::parse(parts.next().unwrap());
 
-pub fn part1(input: &str) -> String {
-    let (initial_stacks, moves) = parse_input(input);
+        let moves = parts.next().unwrap().lines().map(Move::parse).collect();
 
-    let mut stacks = initial_stacks.clone();
-    moves.iter().for_each(|mv| stacks.make_move(mv));
-    stacks.top()
-}
+        Ok((stacks, moves))
+    }
 
-pub fn part2(input: &str) -> String {
-    let (initial_stacks, moves) = parse_input(input);
+    fn part1((initial_stacks, moves): &Self::Input) -> Result<Answer, SolveError> {
+        let mut stacks = initial_stacks.clone();
+        moves.iter().for_each(|mv| stacks.make_move(mv));
+        Ok(stacks.top().into())
+    }
 
-    let mut stacks = initial_stacks.clone();
-    moves.iter().for_each(|mv| stacks.make_move_at_once(mv));
-    stacks.top()
+    fn part2((initial_stacks, moves): &Self::Input) -> Result<Answer, SolveError> {
+        let mut stacks = initial_stacks.clone();
+        moves.iter().for_each(|mv| stacks.make_move_at_once(mv));
+        Ok(stacks.top().into())
+    }
 }
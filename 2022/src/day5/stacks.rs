@@ -9,7 +9,7 @@ impl Stacks {
 
         let mut v = vec![vec![]; count];
 
-        while let Some(line) = iter.next() {
+        for line in iter {
             let mut chars = line.trim_end().chars();
             chars.next();
             let mut stack_idx = 0;
@@ -57,7 +57,7 @@ impl Stacks {
     pub fn top(&self) -> String {
         self.0
             .iter()
-            .filter(|stack| stack.len() > 0)
+            .filter(|stack| !stack.is_empty())
             .map(|stack| stack.last().unwrap())
             .collect()
     }
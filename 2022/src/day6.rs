@@ -1,15 +1,16 @@
 use std::collections::{HashSet, LinkedList};
 
+use aoc_core::{Answer, Solution, SolveError};
+
 fn find_distinct_n(input: &str, n: usize) -> usize {
-    let mut iter = input.chars().enumerate();
     let mut buffer = LinkedList::new();
     let mut set = HashSet::new();
 
-    while let Some((i, c)) = iter.next() {
+    for (i, c) in input.chars().enumerate() {
         buffer.push_back(c);
         if buffer.len() == n {
             set.clear();
-            if buffer.iter().fold(true, |res, c| set.insert(*c) && res) {
+            if buffer.iter().all(|c| set.insert(*c)) {
                 return i + 1;
             }
             buffer.pop_front();
@@ -19,10 +20,20 @@ fn find_distinct_n(input: &str, n: usize) -> usize {
     panic!("oof");
 }
 
-pub fn part1(input: &str) -> usize {
-    find_distinct_n(input, 4)
-}
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input = String;
+
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        Ok(String::from(input.trim_end()))
+    }
+
+    fn part1(signal: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(find_distinct_n(signal, 4).into())
+    }
 
-pub fn part2(input: &str) -> usize {
-    find_distinct_n(input, 14)
+    fn part2(signal: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(find_distinct_n(signal, 14).into())
+    }
 }
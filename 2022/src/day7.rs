@@ -1,37 +1,43 @@
 mod tree;
 
+use aoc_core::{Answer, Solution, SolveError};
+
 use self::tree::Node;
 use std::{cell::RefCell, rc::Rc};
 
-fn parse_input(input: &str) -> Rc<RefCell<Node>> {
-    tree::parse(input)
-}
-
-pub fn part1(input: &str) -> u32 {
-    let root = parse_input(input);
-
-    let mut nodes = vec![Rc::clone(&root)];
-    nodes.extend(root.borrow().flatten_children());
-
-    nodes
-        .iter()
-        .map(|node| node.borrow_mut().size())
-        .filter(|size| *size <= 100000)
-        .sum()
-}
-
-pub fn part2(input: &str) -> u32 {
-    let root = parse_input(input);
-
-    let mut nodes = vec![Rc::clone(&root)];
-    nodes.extend(root.borrow().flatten_children());
-
-    let needed = 30000000 - (70000000 - root.borrow_mut().size());
-
-    nodes
-        .iter()
-        .map(|node| node.borrow_mut().size())
-        .filter(|size| *size >= needed)
-        .min()
-        .unwrap()
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input = Rc<RefCell<Node>>;
+
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        Ok(tree::parse(input))
+    }
+
+    fn part1(root: &Self::Input) -> Result<Answer, SolveError> {
+        let mut nodes = vec![Rc::clone(root)];
+        nodes.extend(root.borrow().flatten_children());
+
+        Ok(nodes
+            .iter()
+            .map(|node| node.borrow_mut().size())
+            .filter(|size| *size <= 100000)
+            .sum::<u32>()
+            .into())
+    }
+
+    fn part2(root: &Self::Input) -> Result<Answer, SolveError> {
+        let mut nodes = vec![Rc::clone(root)];
+        nodes.extend(root.borrow().flatten_children());
+
+        let needed = 30000000 - (70000000 - root.borrow_mut().size());
+
+        Ok(nodes
+            .iter()
+            .map(|node| node.borrow_mut().size())
+            .filter(|size| *size >= needed)
+            .min()
+            .unwrap()
+            .into())
+    }
 }
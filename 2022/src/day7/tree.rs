@@ -84,7 +84,7 @@ pub fn parse(input: &str) -> Rc<RefCell<Node>> {
     build_nodes(parse_commands(input))
 }
 
-fn parse_commands(input: &str) -> Vec<Command> {
+fn parse_commands(input: &str) -> Vec<Command<'_>> {
     let mut cmds = Vec::new();
     let mut lines = input.lines().peekable();
 
@@ -118,9 +118,9 @@ fn build_nodes(cmds: Vec<Command>) -> Rc<RefCell<Node>> {
     let root = Rc::new(RefCell::new(Node::new()));
     let mut stack: Vec<Rc<RefCell<Node>>> = Vec::new();
 
-    let mut iter = cmds.iter();
+    let iter = cmds.iter();
 
-    while let Some(cmd) = iter.next() {
+    for cmd in iter {
         match cmd {
             Command::MoveToRoot => stack.clear(),
             Command::MoveUp => _ = stack.pop(),
@@ -1,100 +1,93 @@
 use std::collections::HashSet;
 
+use aoc_core::{Answer, Grid, Solution, SolveError};
+
 type TreeData = (usize, usize, u8);
 
-fn parse_input(input: &str) -> Vec<Vec<TreeData>> {
-    input
-        .lines()
-        .enumerate()
-        .map(|(row, line)| {
-            line.chars()
-                .enumerate()
-                .map(|(col, char)| (row, col, char.to_digit(10).unwrap() as u8))
-                .collect()
-        })
-        .collect()
-}
+pub struct Day8;
 
-fn get_column<T: Copy>(col_index: usize, input: &Vec<Vec<T>>) -> Vec<T> {
-    input.iter().fold(Vec::new(), |mut col, row| {
-        col.push(*row.get(col_index).unwrap());
-        col
-    })
-}
+impl Solution for Day8 {
+    type Input = Grid<TreeData>;
 
-pub fn part1(input: &str) -> usize {
-    let height_map = parse_input(input);
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        let heights = Grid::parse(input, |c| c.to_digit(10).unwrap() as u8);
+        Ok(Grid::from_fn(
+            heights.width(),
+            heights.height(),
+            |(r, c)| (r, c, heights[(r, c)]),
+        ))
+    }
 
-    let mut visible = HashSet::new();
+    fn part1(height_map: &Self::Input) -> Result<Answer, SolveError> {
+        let mut visible = HashSet::new();
 
-    let mut fold = |highest: Option<u8>, (r, c, tree): &TreeData| match highest {
-        None => {
-            visible.insert((*r, *c));
-            Some(*tree)
-        }
-        Some(highest) => {
-            if highest < *tree {
+        let mut fold = |highest: Option<u8>, (r, c, tree): &TreeData| match highest {
+            None => {
                 visible.insert((*r, *c));
                 Some(*tree)
-            } else {
-                Some(highest)
             }
-        }
-    };
+            Some(highest) => {
+                if highest < *tree {
+                    visible.insert((*r, *c));
+                    Some(*tree)
+                } else {
+                    Some(highest)
+                }
+            }
+        };
 
-    height_map.iter().for_each(|row| {
-        row.iter().fold(None, &mut fold);
-        row.iter().rev().fold(None, &mut fold);
-    });
+        height_map.rows().for_each(|row| {
+            row.iter().fold(None, &mut fold);
+            row.iter().rev().fold(None, &mut fold);
+        });
 
-    let mut i = 0;
-    while i < height_map.first().unwrap().len() {
-        let col: Vec<TreeData> = get_column(i, &height_map);
+        height_map.columns().for_each(|col| {
+            let col: Vec<&TreeData> = col.collect();
+            col.iter().copied().fold(None, &mut fold);
+            col.iter().copied().rev().fold(None, &mut fold);
+        });
 
-        col.iter().fold(None, &mut fold);
-        col.iter().rev().fold(None, &mut fold);
-        i += 1;
+        Ok(visible.len().into())
     }
 
-    visible.len()
-}
-
-pub fn part2(input: &str) -> usize {
-    let height_map = parse_input(input);
-
-    height_map.iter().fold(0, |score, row| {
-        let row_best = row.iter().fold(0, |score, t| {
-            let left = t.1 - find_b(t.2, t.1, row).unwrap_or(&(0, 0, 0)).1;
-            let right = find_f(t.2, t.1, row).unwrap_or(&(0, row.len() - 1, 0)).1 - t.1;
-
-            let col: Vec<TreeData> = get_column(t.1, &height_map);
-            let top = t.0 - find_b(t.2, t.0, &col).unwrap_or(&(0, 0, 0)).0;
-            let bottom = find_f(t.2, t.0, &col).unwrap_or(&(col.len() - 1, 0, 0)).0 - t.0;
-
-            let s = left * right * top * bottom;
-
-            if s > score {
-                s
-            } else {
-                score
-            }
-        });
-
-        if row_best > score {
-            row_best
-        } else {
-            score
-        }
-    })
+    fn part2(height_map: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(height_map
+            .rows()
+            .fold(0, |score, row| {
+                let row_best = row.iter().fold(0, |score, t| {
+                    let left = t.1 - find_b(t.2, t.1, row).unwrap_or(&(0, 0, 0)).1;
+                    let right = find_f(t.2, t.1, row).unwrap_or(&(0, row.len() - 1, 0)).1 - t.1;
+
+                    let col: Vec<TreeData> = height_map.column(t.1).copied().collect();
+                    let top = t.0 - find_b(t.2, t.0, &col).unwrap_or(&(0, 0, 0)).0;
+                    let bottom = find_f(t.2, t.0, &col).unwrap_or(&(col.len() - 1, 0, 0)).0 - t.0;
+
+                    let s = left * right * top * bottom;
+
+                    if s > score {
+                        s
+                    } else {
+                        score
+                    }
+                });
+
+                if row_best > score {
+                    row_best
+                } else {
+                    score
+                }
+            })
+            .into())
+    }
 }
 
-fn find_f(tree_height: u8, from: usize, vec: &Vec<TreeData>) -> Option<&TreeData> {
+fn find_f(tree_height: u8, from: usize, vec: &[TreeData]) -> Option<&TreeData> {
     let mut iter = vec.iter();
     iter.nth(from);
     iter.find(|t| t.2 >= tree_height)
 }
 
-fn find_b(tree_height: u8, from: usize, vec: &Vec<TreeData>) -> Option<&TreeData> {
+fn find_b(tree_height: u8, from: usize, vec: &[TreeData]) -> Option<&TreeData> {
     vec.iter().take(from).rev().find(|t| t.2 >= tree_height)
 }
 
@@ -102,15 +95,6 @@ fn find_b(tree_height: u8, from: usize, vec: &Vec<TreeData>) -> Option<&TreeData
 mod tests {
     use super::*;
 
-    #[test]
-    fn can_create_column() {
-        let data = vec![vec![1, 2, 3, 4], vec![5, 6, 7, 8], vec![9, 10, 11, 12]];
-        assert_eq!(get_column(0, &data), vec![1, 5, 9]);
-        assert_eq!(get_column(1, &data), vec![2, 6, 10]);
-        assert_eq!(get_column(2, &data), vec![3, 7, 11]);
-        assert_eq!(get_column(3, &data), vec![4, 8, 12]);
-    }
-
     #[test]
     fn can_find_forwards() {
         let vec = vec![(0, 0, 1), (0, 1, 2), (0, 2, 3)];
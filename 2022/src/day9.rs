@@ -1,26 +1,35 @@
 mod rope;
 
-use self::rope::{Direction, Move, Point, Rope};
+use aoc_core::{Answer, Direction, Solution, SolveError};
 
-fn parse_input(input: &str) -> Vec<Move> {
-    input
-        .lines()
-        .map(|line| (Direction::parse(&line[0..1]), line[2..].parse().unwrap()))
-        .collect()
-}
+use self::rope::{Move, Point, Rope};
 
-pub fn part1(input: &str) -> usize {
-    let motions = parse_input(input);
+pub struct Day9;
 
-    let mut rope = Rope::new(vec![Point::new(0, 0); 2]);
-    motions.iter().for_each(|mv| rope.make_move(mv));
-    rope.get_tail_history_count()
-}
+impl Solution for Day9 {
+    type Input = Vec<Move>;
+
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        Ok(input
+            .lines()
+            .map(|line| {
+                (
+                    line.chars().next().and_then(Direction::parse).unwrap(),
+                    line[2..].parse().unwrap(),
+                )
+            })
+            .collect())
+    }
 
-pub fn part2(input: &str) -> usize {
-    let motions = parse_input(input);
+    fn part1(motions: &Self::Input) -> Result<Answer, SolveError> {
+        let mut rope = Rope::new(vec![Point::new(0, 0); 2]);
+        motions.iter().for_each(|mv| rope.make_move(mv));
+        Ok(rope.get_tail_history_count().into())
+    }
 
-    let mut rope = Rope::new(vec![Point::new(0, 0); 10]);
-    motions.iter().for_each(|mv| rope.make_move(mv));
-    rope.get_tail_history_count()
+    fn part2(motions: &Self::Input) -> Result<Answer, SolveError> {
+        let mut rope = Rope::new(vec![Point::new(0, 0); 10]);
+        motions.iter().for_each(|mv| rope.make_move(mv));
+        Ok(rope.get_tail_history_count().into())
+    }
 }
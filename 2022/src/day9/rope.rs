@@ -1,72 +1,9 @@
-use std::{
-    collections::HashSet,
-    ops::{Add, Sub},
-};
+use std::collections::HashSet;
 
-#[derive(Debug)]
-pub enum Direction {
-    Left,
-    Right,
-    Down,
-    Up,
-}
-
-impl Direction {
-    pub fn parse(input: &str) -> Self {
-        match input {
-            "L" => Self::Left,
-            "R" => Self::Right,
-            "D" => Self::Down,
-            "U" => Self::Up,
-            _ => panic!("invalid direction"),
-        }
-    }
-}
+use aoc_core::{Direction, Point2};
 
 pub type Move = (Direction, u8);
-
-#[derive(Copy, Clone, Hash, Eq, PartialEq)]
-pub struct Point {
-    x: i32,
-    y: i32,
-}
-
-impl Point {
-    pub fn new(x: i32, y: i32) -> Self {
-        Point { x, y }
-    }
-
-    fn next_in_dir(&self, direction: &Direction) -> Self {
-        match direction {
-            Direction::Left => self + &Point::new(-1, 0),
-            Direction::Right => self + &Point::new(1, 0),
-            Direction::Down => self + &Point::new(0, -1),
-            Direction::Up => self + &Point::new(0, 1),
-        }
-    }
-}
-
-impl Add for &Point {
-    type Output = Point;
-
-    fn add(self, other: Self) -> Point {
-        Point {
-            x: self.x + other.x,
-            y: self.y + other.y,
-        }
-    }
-}
-
-impl Sub for &Point {
-    type Output = Point;
-
-    fn sub(self, rhs: Self) -> Self::Output {
-        Point {
-            x: self.x - rhs.x,
-            y: self.y - rhs.y,
-        }
-    }
-}
+pub type Point = Point2<i32>;
 
 pub struct Rope {
     parts: Vec<Point>,
@@ -89,19 +26,16 @@ impl Rope {
 
     fn move_part(&mut self, part: usize, direction: &Direction) -> Option<usize> {
         let current = self.parts.get(part).unwrap();
-        let next_pos = match self.parts.get(part - 1) {
+        let next_pos = match part.checked_sub(1).and_then(|last| self.parts.get(last)) {
             Some(last) => {
-                let mut diff = last - current;
-                if diff.x.abs() <= 1 && diff.y.abs() <= 1 {
+                if last.chebyshev(current) <= 1 {
                     return None;
                 }
 
-                diff.x = diff.x.checked_div(diff.x.abs()).unwrap_or(0);
-                diff.y = diff.y.checked_div(diff.y.abs()).unwrap_or(0);
-
-                Some(current + &diff)
+                let diff = *last - *current;
+                Some(*current + Point::new(diff.x.signum(), diff.y.signum()))
             }
-            None => Some(current.next_in_dir(direction)),
+            None => Some(current.step(*direction)),
         };
 
         if let Some(next) = next_pos {
@@ -33,30 +33,67 @@ macro_rules! oof {
     };
 }
 
-#[macro_export]
-macro_rules! build_runner_fn {
-    ($($d:literal => $module:ident),+) => {
-      $(
-        use aoc2022::$module;
-      )+
-
-      fn run(day: u8, part: u8) {
-        let path = format!("./input/day{}.txt", day);
-        let input = std::fs::read_to_string(path).expect("could not read input file");
+aoc_core::register_days!(2022;
+    1 => day1::Day1,
+    2 => day2::Day2,
+    3 => day3::Day3,
+    4 => day4::Day4,
+    5 => day5::Day5,
+    6 => day6::Day6,
+    7 => day7::Day7,
+    8 => day8::Day8,
+    9 => day9::Day9,
+    10 => day10::Day10,
+    11 => day11::Day11,
+    12 => day12::Day12,
+    13 => day13::Day13,
+    14 => day14::Day14,
+    15 => day15::Day15,
+    16 => day16::Day16,
+    17 => day17::Day17,
+    18 => day18::Day18,
+    19 => day19::Day19,
+    20 => day20::Day20,
+    21 => day21::Day21,
+    22 => day22::Day22,
+    23 => day23::Day23,
+    24 => day24::Day24,
+    25 => day25::Day25,
+);
 
-        match day {
-            $(
-              $d if part == 1 => {
-                  let sol = $module::part1(&input);
-                  println!("{}", sol);
-              },
-              $d if part == 2 => {
-                  let sol = $module::part2(&input);
-                  println!("{}", sol);
-              },
-            )+
-            _ => panic!("invalid day or part")
-        }
-      }
-    };
-}
+aoc_core::example_tests!(
+    day1::Day1 { part1 => 24000, part2 => 45000 },
+    day2::Day2 { part1 => 15, part2 => 12 },
+    day3::Day3 { part1 => 157, part2 => 70 },
+    day4::Day4 { part1 => 2, part2 => 4 },
+    day5::Day5 { part1 => "CMZ", part2 => "MCD" },
+    day6::Day6 { part1 => 7, part2 => 19 },
+    day7::Day7 { part1 => 95437, part2 => 24933642 },
+    day8::Day8 { part1 => 21, part2 => 8 },
+    day9::Day9 { part1 => 13, part2 in "day9-2.txt" => 36 },
+    day10::Day10 {
+        part1 => 13140,
+        part2 => aoc_core::Answer::grid([
+            "##..##..##..##..##..##..##..##..##..##..",
+            "###...###...###...###...###...###...###.",
+            "####....####....####....####....####....",
+            "#####.....#####.....#####.....#####.....",
+            "######......######......######......####",
+            "#######.......#######.......#######.....",
+        ]),
+    },
+    day11::Day11 { part1 => 10605, part2 => 2713310158_u64 },
+    day12::Day12 { part1 => 31, part2 => 29 },
+    day13::Day13 { part1 => 13, part2 => 140 },
+    day14::Day14 { part1 => 24, part2 => 93 },
+    day16::Day16 { part1 => 1651, part2 => 1707 },
+    day17::Day17 { part1 => 3068, part2 => 1514285714288_u64 },
+    day18::Day18 { part1 => 64, part2 => 58 },
+    day19::Day19 { part1 => 33, part2 => 3472 },
+    day20::Day20 { part1 => 3, part2 => 1623178306 },
+    day21::Day21 { part1 => 152, part2 => 301 },
+    day22::Day22 { part1 => 6032, part2 => 5031 },
+    day23::Day23 { part1 => 110, part2 => 20 },
+    day24::Day24 { part1 => 18, part2 => 54 },
+    day25::Day25 { part1 => "2=-1=0" },
+);
@@ -1,64 +1,76 @@
 use std::collections::HashMap;
 
+use aoc_core::{Answer, Solution, SolveError};
 use regex::Regex;
 
-pub fn part1(input: &str) -> i32 {
-    let re = Regex::new(r"^\D*(\d).*?(\d)?\D*$").unwrap();
+pub struct Day1;
 
-    input
-        .lines()
-        .map(|line| {
-            let cap = re.captures(line).unwrap();
-            let mut num: String = cap.get(1).unwrap().as_str().to_owned();
+impl Solution for Day1 {
+    type Input = String;
 
-            if let Some(second) = cap.get(2) {
-                num.push_str(second.as_str())
-            } else {
-                num.push_str(cap.get(1).unwrap().as_str())
-            }
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        Ok(input.to_owned())
+    }
 
-            num.parse::<i32>().unwrap()
-        })
-        .reduce(|sum, current| sum + current)
-        .unwrap()
-}
+    fn part1(input: &Self::Input) -> Result<Answer, SolveError> {
+        let re = Regex::new(r"^\D*(\d).*?(\d)?\D*$").unwrap();
+
+        Ok(input
+            .lines()
+            .map(|line| {
+                let cap = re.captures(line).unwrap();
+                let mut num: String = cap.get(1).unwrap().as_str().to_owned();
+
+                if let Some(second) = cap.get(2) {
+                    num.push_str(second.as_str())
+                } else {
+                    num.push_str(cap.get(1).unwrap().as_str())
+                }
 
-pub fn part2(input: &str) -> i32 {
-    let re_first = Regex::new(r"^.*?(\d|one|two|three|four|five|six|seven|eight|nine)").unwrap();
-    let re_last = Regex::new(r".*(\d|one|two|three|four|five|six|seven|eight|nine)").unwrap();
-    let m = HashMap::from([
-        ("one", 1),
-        ("two", 2),
-        ("three", 3),
-        ("four", 4),
-        ("five", 5),
-        ("six", 6),
-        ("seven", 7),
-        ("eight", 8),
-        ("nine", 9),
-    ]);
+                num.parse::<i32>().unwrap()
+            })
+            .sum::<i32>()
+            .into())
+    }
 
-    input
-        .lines()
-        .map(|line| {
-            let first_digit = re_first.captures(line).unwrap().get(1).unwrap().as_str();
-            let second_digit = re_last.captures(line).unwrap().get(1).unwrap().as_str();
+    fn part2(input: &Self::Input) -> Result<Answer, SolveError> {
+        let re_first =
+            Regex::new(r"^.*?(\d|one|two|three|four|five|six|seven|eight|nine)").unwrap();
+        let re_last = Regex::new(r".*(\d|one|two|three|four|five|six|seven|eight|nine)").unwrap();
+        let m = HashMap::from([
+            ("one", 1),
+            ("two", 2),
+            ("three", 3),
+            ("four", 4),
+            ("five", 5),
+            ("six", 6),
+            ("seven", 7),
+            ("eight", 8),
+            ("nine", 9),
+        ]);
 
-            let mut num = 10
-                * if let Some(mapped) = m.get(first_digit) {
+        Ok(input
+            .lines()
+            .map(|line| {
+                let first_digit = re_first.captures(line).unwrap().get(1).unwrap().as_str();
+                let second_digit = re_last.captures(line).unwrap().get(1).unwrap().as_str();
+
+                let mut num = 10
+                    * if let Some(mapped) = m.get(first_digit) {
+                        *mapped
+                    } else {
+                        first_digit.parse::<i32>().unwrap().to_owned()
+                    };
+
+                num += if let Some(mapped) = m.get(second_digit) {
                     *mapped
                 } else {
-                    first_digit.parse::<i32>().unwrap().to_owned()
+                    second_digit.parse::<i32>().unwrap().to_owned()
                 };
 
-            num += if let Some(mapped) = m.get(second_digit) {
-                *mapped
-            } else {
-                second_digit.parse::<i32>().unwrap().to_owned()
-            };
-
-            num
-        })
-        .reduce(|sum, current| sum + current)
-        .unwrap()
+                num
+            })
+            .sum::<i32>()
+            .into())
+    }
 }
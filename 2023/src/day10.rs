@@ -1,78 +1,17 @@
 use std::collections::{HashMap, HashSet, LinkedList};
 
+use aoc_core::{Answer, Grid, Pos, Solution, SolveError};
 use itertools::Itertools;
 
-fn parse_input(input: &str) -> ((isize, isize), Vec<Vec<char>>) {
-    let mut start = (0, 0);
-
-    let map = input
-        .trim_end()
-        .lines()
-        .enumerate()
-        .map(|(row, line)| {
-            line.chars()
-                .enumerate()
-                .inspect(|(col, char)| {
-                    if *char == 'S' {
-                        start = (row as isize, *col as isize)
-                    }
-                })
-                .map(|(_, char)| char)
-                .collect()
-        })
-        .collect();
-
-    (start, map)
-}
-
-pub fn part1(input: &str) -> i32 {
-    let (start, map) = parse_input(input);
-
-    let (_, counter) = find_loop(start, &map);
-    (counter - 1) / 2 + 1
-}
-
-
-pub fn part2(input: &str) -> i32 {
-    let (start, map) = parse_input(input);
-
-    let (loop_parts, _) = find_loop(start, &map);
-    let (mut zoomed_map, loop_parts) = zoom_in(&map, &loop_parts);
-
-    zoomed_map.insert(0, vec!['#'; zoomed_map.first().unwrap().len()]);
-    zoomed_map.iter_mut().for_each(|row| row.insert(0, '#'));
-
-    let loop_parts: HashSet<(isize, isize)> = loop_parts
-        .into_iter()
-        .map(|(r, c)| (r + 1, c + 1))
-        .collect();
-
-    let flooded = flood(&zoomed_map, &loop_parts);
-    zoomed_map.iter().enumerate().fold(0, |sum, (r_id, row)| {
-        sum + row
-            .iter()
-            .enumerate()
-            .filter(|(c_id, val)| {
-                let pos = (r_id as isize, *c_id as isize);
-                !flooded.contains(&pos) && !loop_parts.contains(&pos) && **val != '#'
-            })
-            .fold(0, |r_sum, _| r_sum + 1)
-    })
-}
-
-
 const DIR: [(isize, isize); 4] = [(-1, 0), (0, -1), (1, 0), (0, 1)]; // N, W, S, E
 
-fn find_connecting((row, col): (isize, isize), map: &Vec<Vec<char>>) -> Vec<(isize, isize)> {
-    let curr_val = *map.get(row as usize).unwrap().get(col as usize).unwrap();
+fn find_connecting(pos: Pos, map: &Grid<char>) -> Vec<Pos> {
+    let curr_val = map[pos];
     DIR.iter()
-        .map(|(r, c)| (row + *r, col + *c))
         .enumerate()
-        .filter(|(_, (r, c))| {
-            *r >= 0 && *c >= 0 && *r < map.len() as isize && *c < map.get(0).unwrap().len() as isize
-        })
-        .filter(|(dir_id, (r, c))| {
-            let val = *map.get(*r as usize).unwrap().get(*c as usize).unwrap();
+        .filter_map(|(dir_id, dir)| Some((dir_id, map.offset(pos, *dir)?)))
+        .filter(|(dir_id, next)| {
+            let val = map[*next];
 
             match (curr_val, *dir_id) {
                 ('S' | '|' | 'L' | 'J', 0) => val == '|' || val == '7' || val == 'F' || val == 'S',
@@ -86,7 +25,7 @@ fn find_connecting((row, col): (isize, isize), map: &Vec<Vec<char>>) -> Vec<(isi
         .collect()
 }
 
-fn find_loop(start: (isize, isize), map: &Vec<Vec<char>>) -> (HashSet<(isize, isize)>, i32) {
+fn find_loop(start: Pos, map: &Grid<char>) -> (HashSet<Pos>, i32) {
     let mut curr = Some(start);
     let mut counter = 0;
     let mut visited = HashSet::new();
@@ -103,18 +42,17 @@ fn find_loop(start: (isize, isize), map: &Vec<Vec<char>>) -> (HashSet<(isize, is
     (visited, counter)
 }
 
-fn find_real_s(map: &Vec<Vec<char>>, pos: (isize, isize)) -> char {
+fn find_real_s(map: &Grid<char>, pos: Pos) -> char {
     let ord: HashMap<char, u8> =
         HashMap::from_iter("|-LJ7F".chars().enumerate().map(|(id, c)| (c, id as u8)));
 
-    let ((mut r1, mut c1), (mut r2, mut c2)): ((isize, isize), (isize, isize)) =
-        find_connecting(pos, map)
-            .into_iter()
-            .collect_tuple()
-            .unwrap();
+    let ((mut r1, mut c1), (mut r2, mut c2)): (Pos, Pos) = find_connecting(pos, map)
+        .into_iter()
+        .collect_tuple()
+        .unwrap();
 
-    let mut v1 = *map.get(r1 as usize).unwrap().get(c1 as usize).unwrap();
-    let mut v2 = *map.get(r2 as usize).unwrap().get(c2 as usize).unwrap();
+    let mut v1 = map[(r1, c1)];
+    let mut v2 = map[(r2, c2)];
 
     if ord.get(&v1).unwrap() > ord.get(&v2).unwrap() {
         (v2, v1) = (v1, v2);
@@ -200,82 +138,59 @@ fn find_real_s(map: &Vec<Vec<char>>, pos: (isize, isize)) -> char {
     }
 }
 
-fn zoom_in(
-    map: &Vec<Vec<char>>,
-    loop_parts: &HashSet<(isize, isize)>,
-) -> (Vec<Vec<char>>, HashSet<(isize, isize)>) {
-    let mut zoomed: Vec<Vec<char>> = vec![0; map.len() * 2]
-        .into_iter()
-        .map(|_| vec!['#'; map.first().unwrap().len() * 2])
-        .collect();
-
-    let mut new_loop_parts: HashSet<(isize, isize)> = HashSet::new();
+/// Doubles the map so the gaps between pipes become tiles the outside can flood through,
+/// with an extra row and column of ground on the top and left to start the flood from.
+fn zoom_in(map: &Grid<char>, loop_parts: &HashSet<Pos>) -> (Grid<char>, HashSet<Pos>) {
+    let mut zoomed = Grid::new(map.width() * 2 + 1, map.height() * 2 + 1, '#');
+    let mut new_loop_parts: HashSet<Pos> = HashSet::new();
 
-    map.iter().enumerate().for_each(|(row, line)| {
-        line.into_iter().enumerate().for_each(|(col, val)| {
-            let pos = (row as isize, col as isize);
-            *zoomed.get_mut(row * 2).unwrap().get_mut(col * 2).unwrap() = *val;
+    map.iter().for_each(|(pos, val)| {
+        let (row, col) = (pos.0 * 2 + 1, pos.1 * 2 + 1);
+        zoomed[(row, col)] = *val;
 
-            if !loop_parts.contains(&pos) {
-                return;
-            }
+        if !loop_parts.contains(&pos) {
+            return;
+        }
 
-            new_loop_parts.insert((pos.0 * 2, pos.1 * 2));
+        new_loop_parts.insert((row, col));
 
-            let mut c = *val;
-            if c == 'S' {
-                c = find_real_s(map, pos)
-            }
+        let mut c = *val;
+        if c == 'S' {
+            c = find_real_s(map, pos)
+        }
 
-            let (right, down) = match c {
-                '|' => ('#', '|'),
-                '-' => ('-', '#'),
-                'L' => ('-', '#'),
-                'J' => ('#', '#'),
-                '7' => ('#', '|'),
-                'F' => ('-', '|'),
-                _ => panic!("can't happen"),
-            };
-
-            if right != '#' {
-                new_loop_parts.insert((pos.0 * 2, pos.1 * 2 + 1));
-                *zoomed
-                    .get_mut(row * 2)
-                    .unwrap()
-                    .get_mut(col * 2 + 1)
-                    .unwrap() = right;
-            }
+        let (right, down) = match c {
+            '|' => ('#', '|'),
+            '-' => ('-', '#'),
+            'L' => ('-', '#'),
+            'J' => ('#', '#'),
+            '7' => ('#', '|'),
+            'F' => ('-', '|'),
+            _ => panic!("can't happen"),
+        };
+
+        if right != '#' {
+            new_loop_parts.insert((row, col + 1));
+            zoomed[(row, col + 1)] = right;
+        }
 
-            if down != '#' {
-                new_loop_parts.insert((pos.0 * 2 + 1, pos.1 * 2));
-                *zoomed
-                    .get_mut(row * 2 + 1)
-                    .unwrap()
-                    .get_mut(col * 2)
-                    .unwrap() = down;
-            }
-        });
+        if down != '#' {
+            new_loop_parts.insert((row + 1, col));
+            zoomed[(row + 1, col)] = down;
+        }
     });
 
     (zoomed, new_loop_parts)
 }
 
-fn flood(map: &Vec<Vec<char>>, loop_parts: &HashSet<(isize, isize)>) -> HashSet<(isize, isize)> {
+fn flood(map: &Grid<char>, loop_parts: &HashSet<Pos>) -> HashSet<Pos> {
     let mut queue = LinkedList::new();
     let mut flooded = HashSet::new();
 
     queue.push_back((0, 0));
-    while !queue.is_empty() {
-        let (row, col) = queue.pop_front().unwrap();
-        let next: Vec<(isize, isize)> = DIR
-            .iter()
-            .map(|(r, c)| (row + *r, col + *c))
-            .filter(|(r, c)| {
-                *r >= 0
-                    && *c >= 0
-                    && *r < map.len() as isize
-                    && *c < map.first().unwrap().len() as isize
-            })
+    while let Some(pos) = queue.pop_front() {
+        let next: Vec<Pos> = map
+            .neighbors4(pos)
             .filter(|pos| !flooded.contains(pos) && !loop_parts.contains(pos))
             .collect();
 
@@ -287,3 +202,35 @@ fn flood(map: &Vec<Vec<char>>, loop_parts: &HashSet<(isize, isize)>) -> HashSet<
 
     flooded
 }
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = (Pos, Grid<char>);
+
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        let map = Grid::parse(input.trim_end(), |c| c);
+        let start = map.position(|c| *c == 'S').unwrap();
+
+        Ok((start, map))
+    }
+
+    fn part1((start, map): &Self::Input) -> Result<Answer, SolveError> {
+        let (_, counter) = find_loop(*start, map);
+        Ok(((counter - 1) / 2 + 1).into())
+    }
+
+    fn part2((start, map): &Self::Input) -> Result<Answer, SolveError> {
+        let (loop_parts, _) = find_loop(*start, map);
+        let (zoomed_map, loop_parts) = zoom_in(map, &loop_parts);
+
+        let flooded = flood(&zoomed_map, &loop_parts);
+        Ok(zoomed_map
+            .iter()
+            .filter(|(pos, val)| {
+                !flooded.contains(pos) && !loop_parts.contains(pos) && **val != '#'
+            })
+            .count()
+            .into())
+    }
+}
@@ -1,53 +1,24 @@
-use std::collections::HashSet;
+use aoc_core::{Answer, Grid, Pos, Solution, SolveError};
 
-fn parse_input(input: &str) -> Vec<Vec<char>> {
-    input.lines().map(|line| line.chars().collect()).collect()
-}
-
-pub fn part1(input: &str) -> usize {
-    let map = parse_input(input);
-    find_distances(&map, 2)
-}
-
-pub fn part2(input: &str) -> usize {
-    let map = parse_input(input);
-    find_distances(&map, 1000000)
-}
-
-fn find_distances(map: &Vec<Vec<char>>, expansion_factor: usize) -> usize {
+fn find_distances(map: &Grid<char>, expansion_factor: usize) -> usize {
     let empty_rows: Vec<usize> = map
-        .iter()
+        .rows()
         .enumerate()
         .filter(|(_, row)| row.iter().all(|v| *v == '.'))
         .map(|(r_id, _)| r_id)
         .collect();
 
-    let mut empty_cols: HashSet<usize> = map
-        .first()
-        .unwrap()
-        .iter()
+    let empty_cols: Vec<usize> = map
+        .columns()
         .enumerate()
-        .map(|(c_id, _)| c_id)
+        .filter_map(|(c_id, mut col)| col.all(|v| *v == '.').then_some(c_id))
         .collect();
 
-    map.iter().for_each(|row| {
-        row.iter()
-            .enumerate()
-            .filter(|(_, v)| **v == '#')
-            .for_each(|(c_id, _)| {
-                empty_cols.remove(&c_id);
-            })
-    });
-
-    let mut galaxies: Vec<(usize, usize)> = Vec::new();
-    map.iter().enumerate().for_each(|(row_id, row)| {
-        row.iter()
-            .enumerate()
-            .filter(|(_, val)| **val == '#')
-            .for_each(|(col_id, _)| {
-                galaxies.push((row_id, col_id));
-            })
-    });
+    let galaxies: Vec<Pos> = map
+        .iter()
+        .filter(|(_, val)| **val == '#')
+        .map(|(pos, _)| pos)
+        .collect();
 
     let mut sum_dist = 0;
 
@@ -75,3 +46,21 @@ fn find_distances(map: &Vec<Vec<char>>, expansion_factor: usize) -> usize {
 
     sum_dist
 }
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Grid<char>;
+
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        Ok(Grid::parse(input, |c| c))
+    }
+
+    fn part1(map: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(find_distances(map, 2).into())
+    }
+
+    fn part2(map: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(find_distances(map, 1000000).into())
+    }
+}
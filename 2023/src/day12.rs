@@ -1,53 +1,9 @@
 use std::collections::HashMap;
 
+use aoc_core::{Answer, Solution, SolveError};
 use itertools::Itertools;
 
-fn parse_input(input: &str) -> Vec<(Vec<char>, Vec<usize>, usize)> {
-    input
-        .lines()
-        .map(|line| {
-            let (known, sums): (&str, &str) = line.split_whitespace().collect_tuple().unwrap();
-            let mut total = 0;
-            let ordered_parts: Vec<usize> = sums
-                .split(",")
-                .map(|num| num.parse::<usize>().unwrap())
-                .inspect(|num| total += num)
-                .collect();
-
-            let known: Vec<char> = known.chars().collect();
-            let unordered_parts = known.len() as usize - total;
-
-            (known, ordered_parts, unordered_parts)
-        })
-        .collect()
-}
-
-pub fn part1(input: &str) -> usize {
-    let lines = parse_input(input);
-
-    lines
-        .iter()
-        .map(|(known, ordered, unordered)| {
-            let mut cache = HashMap::new();
-            count(known, ordered, &mut cache, (0, 0, *unordered, false))
-        })
-        .fold(0, |sum, count| sum + count)
-}
-
-pub fn part2(input: &str) -> usize {
-    let lines = parse_input(input);
-
-    lines
-        .iter()
-        .map(|(known, ordered, unordered)| unfold(known, ordered, *unordered))
-        .map(|(known, ordered, unordered)| {
-            let mut cache = HashMap::new();
-            count(&known, &ordered, &mut cache, (0, 0, unordered, false))
-        })
-        .fold(0, |sum, count| sum + count)
-}
-
-fn can_fit(next_ordered_part_size: usize, known: &Vec<char>, pos: usize) -> bool {
+fn can_fit(next_ordered_part_size: usize, known: &[char], pos: usize) -> bool {
     next_ordered_part_size > 0
         && known.len() >= pos + next_ordered_part_size
         && known[pos..pos + next_ordered_part_size]
@@ -71,10 +27,7 @@ fn count(
         return 0;
     }
 
-    let next_ordered_part_size = ordered_parts
-        .get(next_ordered_part)
-        .map(|size| *size)
-        .unwrap_or_else(|| 0);
+    let next_ordered_part_size = ordered_parts.get(next_ordered_part).copied().unwrap_or(0);
 
     let pos_char = *known.get(pos).unwrap();
 
@@ -129,7 +82,7 @@ fn unfold(
 ) -> (Vec<char>, Vec<usize>, usize) {
     let known = vec![known; 5]
         .into_iter()
-        .map(|v| String::from_iter(v))
+        .map(String::from_iter)
         .join("?")
         .chars()
         .collect();
@@ -137,8 +90,57 @@ fn unfold(
     let ordered_parts = vec![ordered_parts; 5]
         .into_iter()
         .flatten()
-        .map(|v| *v)
+        .copied()
         .collect();
 
     (known, ordered_parts, 5 * unordered_parts + 4)
 }
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Vec<(Vec<char>, Vec<usize>, usize)>;
+
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        Ok(input
+            .lines()
+            .map(|line| {
+                let (known, sums): (&str, &str) = line.split_whitespace().collect_tuple().unwrap();
+                let mut total = 0;
+                let ordered_parts: Vec<usize> = sums
+                    .split(",")
+                    .map(|num| num.parse::<usize>().unwrap())
+                    .inspect(|num| total += num)
+                    .collect();
+
+                let known: Vec<char> = known.chars().collect();
+                let unordered_parts = known.len() - total;
+
+                (known, ordered_parts, unordered_parts)
+            })
+            .collect())
+    }
+
+    fn part1(lines: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(lines
+            .iter()
+            .map(|(known, ordered, unordered)| {
+                let mut cache = HashMap::new();
+                count(known, ordered, &mut cache, (0, 0, *unordered, false))
+            })
+            .sum::<usize>()
+            .into())
+    }
+
+    fn part2(lines: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(lines
+            .iter()
+            .map(|(known, ordered, unordered)| unfold(known, ordered, *unordered))
+            .map(|(known, ordered, unordered)| {
+                let mut cache = HashMap::new();
+                count(&known, &ordered, &mut cache, (0, 0, unordered, false))
+            })
+            .sum::<usize>()
+            .into())
+    }
+}
@@ -1,35 +1,14 @@
-use itertools::Itertools;
-
-fn parse_input(input: &str) -> Vec<(Vec<String>, Vec<String>)> {
-    input
-        .split("\n\n")
-        .map(|pattern| {
-            let rows: Vec<String> = pattern.lines().map(|l| l.to_owned()).collect();
-            let mut cols: Vec<String> = Vec::new();
-
-            for i in 0..rows[0].len() {
-                let mut col = Vec::new();
-                for j in 0..rows.len() {
-                    col.push(rows[j].chars().skip(i).next().unwrap())
-                }
-
-                cols.push(col.into_iter().join(""))
-            }
-
-            (rows, cols)
-        })
-        .collect()
-}
+use aoc_core::{Answer, Grid, Solution, SolveError};
 
 fn find_smudgy_mirror((rows, cols): &(Vec<String>, Vec<String>)) -> (usize, usize) {
-    let mut rows: Vec<String> = rows.iter().map(|s| s.clone()).collect();
-    let mut cols: Vec<String> = cols.iter().map(|s| s.clone()).collect();
+    let mut rows: Vec<String> = rows.to_vec();
+    let mut cols: Vec<String> = cols.to_vec();
 
     let (og_rc, og_cc) = (find_mirror(&rows, None), find_mirror(&cols, None));
 
     for r in 0..rows.len() {
         for c in 0..cols.len() {
-            let char = rows[r].chars().skip(c).next().unwrap().to_string();
+            let char = rows[r].chars().nth(c).unwrap().to_string();
             let replacement = if char == "." { "#" } else { "." };
             rows[r].replace_range(c..c + 1, replacement);
             cols[c].replace_range(r..r + 1, replacement);
@@ -51,12 +30,12 @@ fn find_smudgy_mirror((rows, cols): &(Vec<String>, Vec<String>)) -> (usize, usiz
     panic!("can't happen")
 }
 
-fn find_mirror(vectors: &Vec<String>, skipped: Option<usize>) -> usize {
+fn find_mirror(vectors: &[String], skipped: Option<usize>) -> usize {
     let mut potentials = Vec::new();
 
     for e in 1..vectors.len() {
         let s = e - 1;
-        if skipped.map_or(true, |v| e != v) && vectors[s] == vectors[e] {
+        if (skipped != Some(e)) && vectors[s] == vectors[e] {
             potentials.push((s, e));
         }
     }
@@ -77,22 +56,39 @@ fn find_mirror(vectors: &Vec<String>, skipped: Option<usize>) -> usize {
     0
 }
 
-pub fn part1(input: &str) -> usize {
-    let patterns = parse_input(input);
+pub struct Day13;
 
-    patterns
-        .iter()
-        .map(|(rows, cols)| (find_mirror(rows, None), find_mirror(cols, None)))
-        .map(|(r_c, c_c)| 100 * r_c + c_c)
-        .sum()
-}
+impl Solution for Day13 {
+    type Input = Vec<(Vec<String>, Vec<String>)>;
 
-pub fn part2(input: &str) -> usize {
-    let patterns = parse_input(input);
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        Ok(input
+            .split("\n\n")
+            .map(|pattern| {
+                let pattern = Grid::parse(pattern, |c| c);
+                let rows = pattern.rows().map(|row| row.iter().collect()).collect();
+                let cols = pattern.columns().map(|col| col.collect()).collect();
 
-    patterns
-        .iter()
-        .map(|pattern| find_smudgy_mirror(pattern))
-        .map(|(r_c, c_c)| 100 * r_c + c_c)
-        .sum()
+                (rows, cols)
+            })
+            .collect())
+    }
+
+    fn part1(patterns: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(patterns
+            .iter()
+            .map(|(rows, cols)| (find_mirror(rows, None), find_mirror(cols, None)))
+            .map(|(r_c, c_c)| 100 * r_c + c_c)
+            .sum::<usize>()
+            .into())
+    }
+
+    fn part2(patterns: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(patterns
+            .iter()
+            .map(find_smudgy_mirror)
+            .map(|(r_c, c_c)| 100 * r_c + c_c)
+            .sum::<usize>()
+            .into())
+    }
 }
@@ -1,63 +1,54 @@
-use std::{collections::HashMap, ops::Range};
+use std::ops::Range;
 
-enum Cardinal {
-    N,
-    S,
-    W,
-    E,
-}
-
-fn parse_input(input: &str) -> Vec<Vec<char>> {
-    input.lines().map(|row| row.chars().collect()).collect()
-}
+use aoc_core::{cycle, Answer, Direction, Grid, Pos, Solution, SolveError};
 
-fn tilt(direction: Cardinal, map: &Vec<Vec<char>>) -> Vec<Vec<char>> {
-    let height = map.len();
-    let width = map[0].len();
+fn tilt(direction: Direction, map: &Grid<char>) -> Grid<char> {
+    let height = map.height();
+    let width = map.width();
 
     let mut last_blocks = match direction {
-        Cardinal::N => vec![-1; width],
-        Cardinal::S => vec![height as isize; width],
-        Cardinal::W => vec![-1; height],
-        Cardinal::E => vec![width as isize; height],
+        Direction::N => vec![-1; width],
+        Direction::S => vec![height as isize; width],
+        Direction::W => vec![-1; height],
+        Direction::E => vec![width as isize; height],
     };
     let mut tilted_map = map.clone();
 
     let (outer, inner): (Box<dyn Iterator<Item = usize>>, Range<usize>) = match direction {
-        Cardinal::N => (Box::new(0..height), 0..width),
-        Cardinal::S => (Box::new((0..height).rev()), 0..width),
-        Cardinal::W => (Box::new(0..width), 0..height),
-        Cardinal::E => (Box::new((0..width).rev()), 0..height),
+        Direction::N => (Box::new(0..height), 0..width),
+        Direction::S => (Box::new((0..height).rev()), 0..width),
+        Direction::W => (Box::new(0..width), 0..height),
+        Direction::E => (Box::new((0..width).rev()), 0..height),
     };
 
     for o in outer {
         let inner = inner.clone();
         for i in inner {
             let (row_id, col_id) = match direction {
-                Cardinal::N | Cardinal::S => (o, i),
-                Cardinal::W | Cardinal::E => (i, o),
+                Direction::N | Direction::S => (o, i),
+                Direction::W | Direction::E => (i, o),
             };
 
             let (block_id, block_value) = match direction {
-                Cardinal::N | Cardinal::S => (col_id, row_id),
-                Cardinal::W | Cardinal::E => (row_id, col_id),
+                Direction::N | Direction::S => (col_id, row_id),
+                Direction::W | Direction::E => (row_id, col_id),
             };
 
-            let tile = map[row_id][col_id];
+            let tile = map[(row_id, col_id)];
 
             match tile {
                 '#' => last_blocks[block_id] = block_value as isize,
                 'O' => {
                     let modifier = match direction {
-                        Cardinal::N | Cardinal::W => 1,
-                        Cardinal::S | Cardinal::E => -1,
+                        Direction::N | Direction::W => 1,
+                        Direction::S | Direction::E => -1,
                     };
 
                     let open_position = (last_blocks[block_id] + modifier) as usize;
-                    tilted_map[row_id][col_id] = '.';
+                    tilted_map[(row_id, col_id)] = '.';
                     match direction {
-                        Cardinal::N | Cardinal::S => tilted_map[open_position][col_id] = 'O',
-                        Cardinal::W | Cardinal::E => tilted_map[row_id][open_position] = 'O',
+                        Direction::N | Direction::S => tilted_map[(open_position, col_id)] = 'O',
+                        Direction::W | Direction::E => tilted_map[(row_id, open_position)] = 'O',
                     };
                     last_blocks[block_id] += modifier;
                 }
@@ -69,67 +60,50 @@ fn tilt(direction: Cardinal, map: &Vec<Vec<char>>) -> Vec<Vec<char>> {
     tilted_map
 }
 
-fn get_state(map: &Vec<Vec<char>>) -> Vec<(usize, usize)> {
+fn get_state(map: &Grid<char>) -> Vec<Pos> {
     map.iter()
-        .enumerate()
-        .map(|(row_id, row)| {
-            row.iter()
-                .enumerate()
-                .filter(|(_, tile)| **tile == 'O')
-                .map(|(col_id, _)| (row_id, col_id))
-                .collect::<Vec<(usize, usize)>>()
-        })
-        .flatten()
+        .filter(|(_, tile)| **tile == 'O')
+        .map(|(pos, _)| pos)
         .collect()
 }
 
-pub fn part1(input: &str) -> usize {
-    let map = parse_input(input);
-
-    let tilted_map = tilt(Cardinal::N, &map);
-
-    tilted_map
-        .iter()
-        .enumerate()
-        .map(|(row_id, row)| row.iter().filter(|tile| **tile == 'O').count() * (map.len() - row_id))
-        .sum()
-}
-
-pub fn part2(input: &str) -> usize {
-    let mut map = parse_input(input);
-    let row_size = map.len();
-
-    let total = 1000000000;
-    let rep_size;
-    let rep_start;
-    let mut seen_states = HashMap::new();
-
-    let mut i = 0;
-    loop {
-        let curr_state = get_state(&map);
-        if let Some(last_i) = seen_states.get(&curr_state) {
-            rep_size = i - last_i;
-            rep_start = last_i;
-            break;
-        }
-
-        seen_states.insert(curr_state, i);
+pub struct Day14;
 
-        for dir in [Cardinal::N, Cardinal::W, Cardinal::S, Cardinal::E] {
-            map = tilt(dir, &map);
-        }
+impl Solution for Day14 {
+    type Input = Grid<char>;
 
-        i += 1;
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        Ok(Grid::parse(input, |c| c))
     }
 
-    let idx = ((total - rep_start) % rep_size) + rep_start;
+    fn part1(map: &Self::Input) -> Result<Answer, SolveError> {
+        let tilted_map = tilt(Direction::N, map);
+
+        Ok(tilted_map
+            .rows()
+            .enumerate()
+            .map(|(row_id, row)| {
+                row.iter().filter(|tile| **tile == 'O').count() * (map.height() - row_id)
+            })
+            .sum::<usize>()
+            .into())
+    }
 
-    seen_states
-        .iter()
-        .find(|(_, i)| **i == idx)
-        .unwrap()
-        .0
-        .iter()
-        .map(|(r, _)| row_size - r)
-        .sum()
+    fn part2(map: &Self::Input) -> Result<Answer, SolveError> {
+        let spin = |map: &mut Grid<char>| {
+            for dir in [Direction::N, Direction::W, Direction::S, Direction::E] {
+                *map = tilt(dir, map);
+            }
+        };
+
+        Ok(
+            cycle::fast_forward(map.clone(), 1000000000, spin, get_state, |map| {
+                get_state(map)
+                    .iter()
+                    .map(|(r, _)| (map.height() - r) as i64)
+                    .sum()
+            })
+            .into(),
+        )
+    }
 }
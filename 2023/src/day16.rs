@@ -1,21 +1,12 @@
 use std::collections::{HashSet, LinkedList};
 
-use self::tile::{Cardinal, Tile};
+use aoc_core::{Answer, Direction, Grid, Pos, Solution, SolveError};
 
-mod tile;
-
-fn parse_input(input: &str) -> Vec<Vec<Tile>> {
-    input
-        .lines()
-        .map(|row| row.chars().map(|c| Tile::new(c).unwrap()).collect())
-        .collect()
-}
+use self::tile::Tile;
 
-fn is_valid(map: &Vec<Vec<Tile>>, row: isize, col: isize) -> bool {
-    row >= 0 && col >= 0 && (row as usize) < map.len() && (col as usize) < map[0].len()
-}
+mod tile;
 
-fn simulate(map: &Vec<Vec<Tile>>, start: (isize, isize), from: Cardinal) -> usize {
+fn simulate(map: &Grid<Tile>, start: Pos, from: Direction) -> usize {
     let mut energized = HashSet::new();
     let mut seen = HashSet::new();
     let mut queue = LinkedList::new();
@@ -25,25 +16,23 @@ fn simulate(map: &Vec<Vec<Tile>>, start: (isize, isize), from: Cardinal) -> usiz
     seen.insert((start, from));
 
     while !queue.is_empty() {
-        let ((row, col), from) = queue.pop_front().unwrap();
-        let tile = &map[row as usize][col as usize];
+        let (pos, from) = queue.pop_front().unwrap();
+        let tile = &map[pos];
         let (next, next_2) = tile.next(from);
 
-        let mut process_move = |next: Cardinal| {
-            let (next_row, next_col) = next.move_coord(row, col);
-            let from = next.opposite();
-
-            if !is_valid(map, next_row, next_col) {
+        let mut process_move = |next: Direction| {
+            let Some(next_pos) = map.offset(pos, next.delta()) else {
                 return;
-            }
+            };
+            let from = next.opposite();
 
-            let state = ((next_row, next_col), from);
+            let state = (next_pos, from);
             if seen.contains(&state) {
                 return;
             }
 
             seen.insert(state);
-            energized.insert((next_row, next_col));
+            energized.insert(next_pos);
             queue.push_back(state);
         };
 
@@ -56,30 +45,36 @@ fn simulate(map: &Vec<Vec<Tile>>, start: (isize, isize), from: Cardinal) -> usiz
     energized.len()
 }
 
-pub fn part1(input: &str) -> usize {
-    let map = parse_input(input);
+pub struct Day16;
 
-    simulate(&map, (0, 0), Cardinal::W)
-}
-
-pub fn part2(input: &str) -> usize {
-    let map = parse_input(input);
-
-    let mut max = 0;
+impl Solution for Day16 {
+    type Input = Grid<Tile>;
 
-    for r in 0..map.len() {
-        let size = simulate(&map, (r as isize, 0), Cardinal::W);
-        max = max.max(size);
-        let size = simulate(&map, (r as isize, (map[0].len() - 1) as isize), Cardinal::E);
-        max = max.max(size);
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        Ok(Grid::parse(input, |c| Tile::new(c).unwrap()))
     }
 
-    for c in 0..map[0].len() {
-        let size = simulate(&map, (0, c as isize), Cardinal::N);
-        max = max.max(size);
-        let size = simulate(&map, ((map.len() - 1) as isize, c as isize), Cardinal::S);
-        max = max.max(size);
+    fn part1(map: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(simulate(map, (0, 0), Direction::W).into())
     }
 
-    max
+    fn part2(map: &Self::Input) -> Result<Answer, SolveError> {
+        let mut max = 0;
+
+        for r in 0..map.height() {
+            let size = simulate(map, (r, 0), Direction::W);
+            max = max.max(size);
+            let size = simulate(map, (r, map.width() - 1), Direction::E);
+            max = max.max(size);
+        }
+
+        for c in 0..map.width() {
+            let size = simulate(map, (0, c), Direction::N);
+            max = max.max(size);
+            let size = simulate(map, (map.height() - 1, c), Direction::S);
+            max = max.max(size);
+        }
+
+        Ok(max.into())
+    }
 }
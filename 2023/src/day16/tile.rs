@@ -1,30 +1,4 @@
-#[derive(Clone, Copy, PartialEq, Eq, Hash)]
-pub enum Cardinal {
-    N,
-    W,
-    S,
-    E,
-}
-
-impl Cardinal {
-    pub fn opposite(self) -> Self {
-        match self {
-            Self::N => Self::S,
-            Self::W => Self::E,
-            Self::S => Self::N,
-            Self::E => Self::W,
-        }
-    }
-
-    pub fn move_coord(self, row: isize, col: isize) -> (isize, isize) {
-        match self {
-            Self::N => (row - 1, col),
-            Self::W => (row, col - 1),
-            Self::S => (row + 1, col),
-            Self::E => (row, col + 1),
-        }
-    }
-}
+use aoc_core::Direction;
 
 pub enum Tile {
     Empty,              // '.'
@@ -46,23 +20,25 @@ impl Tile {
         }
     }
 
-    pub fn next(&self, from: Cardinal) -> (Cardinal, Option<Cardinal>) {
+    pub fn next(&self, from: Direction) -> (Direction, Option<Direction>) {
         match (self, from) {
             (Self::Empty, _) => (from.opposite(), None),
-            (Self::ForwardMirror, Cardinal::N) => (Cardinal::W, None),
-            (Self::ForwardMirror, Cardinal::W) => (Cardinal::N, None),
-            (Self::ForwardMirror, Cardinal::S) => (Cardinal::E, None),
-            (Self::ForwardMirror, Cardinal::E) => (Cardinal::S, None),
-            (Self::BackMirror, Cardinal::N) => (Cardinal::E, None),
-            (Self::BackMirror, Cardinal::W) => (Cardinal::S, None),
-            (Self::BackMirror, Cardinal::S) => (Cardinal::W, None),
-            (Self::BackMirror, Cardinal::E) => (Cardinal::N, None),
-            (Self::HorizontalSplitter, Cardinal::N | Cardinal::S) => {
-                (Cardinal::W, Some(Cardinal::E))
+            (Self::ForwardMirror, Direction::N) => (Direction::W, None),
+            (Self::ForwardMirror, Direction::W) => (Direction::N, None),
+            (Self::ForwardMirror, Direction::S) => (Direction::E, None),
+            (Self::ForwardMirror, Direction::E) => (Direction::S, None),
+            (Self::BackMirror, Direction::N) => (Direction::E, None),
+            (Self::BackMirror, Direction::W) => (Direction::S, None),
+            (Self::BackMirror, Direction::S) => (Direction::W, None),
+            (Self::BackMirror, Direction::E) => (Direction::N, None),
+            (Self::HorizontalSplitter, Direction::N | Direction::S) => {
+                (Direction::W, Some(Direction::E))
+            }
+            (Self::HorizontalSplitter, Direction::E | Direction::W) => (from.opposite(), None),
+            (Self::VerticalSplitter, Direction::N | Direction::S) => (from.opposite(), None),
+            (Self::VerticalSplitter, Direction::W | Direction::E) => {
+                (Direction::N, Some(Direction::S))
             }
-            (Self::HorizontalSplitter, Cardinal::E | Cardinal::W) => (from.opposite(), None),
-            (Self::VerticalSplitter, Cardinal::N | Cardinal::S) => (from.opposite(), None),
-            (Self::VerticalSplitter, Cardinal::W | Cardinal::E) => (Cardinal::N, Some(Cardinal::S)),
         }
     }
 }
@@ -1,109 +1,54 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, io};
 
+use aoc_core::{Answer, Cuboid, Interval, Solution, SolveError};
 use itertools::Itertools;
 
-use self::workflow::{Part, Property, Res, Rule, Workflow};
+pub use self::decision::Decisions;
 
-mod workflow;
-
-fn parse_input(input: &str) -> (HashMap<String, Workflow>, Vec<Part>) {
-    let (workflows, parts) = input.split("\n\n").collect_tuple().unwrap();
+use self::workflow::{Part, Workflow};
 
-    let workflows = HashMap::from_iter(workflows.lines().map(|workflow| {
-        let w = Workflow::parse(workflow);
-        (w.copy_id(), w)
-    }));
-
-    let parts = parts.lines().map(|part| Part::parse(part)).collect();
+mod decision;
+mod workflow;
 
-    (workflows, parts)
+/// Writes the workflows as one simplified decision tree.
+pub fn write_decisions(input: &str, out: &mut impl io::Write) -> io::Result<()> {
+    let (decisions, _) = Day19::parse(input)
+        .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err.to_string()))?;
+    write!(out, "{decisions}")
 }
 
-fn find_all_approved(
-    workflows: &HashMap<String, Workflow>,
-    curr: &Workflow,
-    ranges: HashMap<Property, (usize, usize)>,
-) -> usize {
-    let mut accepted = 0;
-    let mut non_matching = HashMap::clone(&ranges);
+pub struct Day19;
 
-    for (rule, result) in curr.get_switch() {
-        let mut matching = HashMap::clone(&non_matching);
+impl Solution for Day19 {
+    type Input = (Decisions, Vec<Part>);
 
-        match rule {
-            Rule::DEFAULT => {}
-            Rule::LT(prop, num) => {
-                let (lower_nm, _) = non_matching.get_mut(prop).unwrap();
-                let (_, upper_m) = matching.get_mut(prop).unwrap();
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        let (workflows, parts) = input.split("\n\n").collect_tuple().unwrap();
 
-                *upper_m = (*num - 1).min(*upper_m);
-                *lower_nm = (*num).max(*lower_nm);
-            }
-            Rule::GT(prop, num) => {
-                let (_, upper_nm) = non_matching.get_mut(prop).unwrap();
-                let (lower_m, _) = matching.get_mut(prop).unwrap();
+        let workflows: HashMap<String, Workflow> =
+            HashMap::from_iter(workflows.lines().map(|workflow| {
+                let w = Workflow::parse(workflow);
+                (w.copy_id(), w)
+            }));
+        let decisions = Decisions::compile(&workflows, "in")?;
 
-                *lower_m = (*num + 1).max(*lower_m);
-                *upper_nm = (*num).min(*upper_nm);
-            }
-        }
+        let parts = parts.lines().map(Part::parse).collect();
 
-        match result {
-            Res::Reject => {}
-            Res::Approve => {
-                // assumes upper >= lower
-                accepted += matching
-                    .into_iter()
-                    .map(|(_, (lower, upper))| upper - lower + 1)
-                    .reduce(|prod, d| prod * d)
-                    .unwrap();
-            }
-            Res::Forward(next_id) => {
-                accepted += find_all_approved(workflows, workflows.get(next_id).unwrap(), matching);
-            }
-        }
+        Ok((decisions, parts))
     }
 
-    accepted
-}
-
-pub fn part1(input: &str) -> usize {
-    let (workflows, parts) = parse_input(input);
-
-    let init_workflow = workflows.get("in").unwrap();
-    let mut sum = 0;
-
-    parts.iter().for_each(|part| {
-        let mut curr_workflow = init_workflow;
-        loop {
-            match curr_workflow.process(part) {
-                Res::Approve => {
-                    sum += part.sum();
-                    break;
-                }
-                Res::Reject => {
-                    break;
-                }
-                Res::Forward(id) => {
-                    curr_workflow = workflows.get(id).unwrap();
-                }
-            }
-        }
-    });
-
-    sum
-}
-
-pub fn part2(input: &str) -> usize {
-    let (workflows, _) = parse_input(input);
-
-    let init_workflow = workflows.get("in").unwrap();
-    let ranges = HashMap::from([
-        (Property::X, (1, 4000)),
-        (Property::M, (1, 4000)),
-        (Property::A, (1, 4000)),
-        (Property::S, (1, 4000)),
-    ]);
+    fn part1((decisions, parts): &Self::Input) -> Result<Answer, SolveError> {
+        Ok(parts
+            .iter()
+            .filter(|part| decisions.accepts(part.ratings()))
+            .map(Part::sum)
+            .sum::<usize>()
+            .into())
+    }
 
-    find_all_approved(&workflows, init_workflow, ranges)
+    fn part2((decisions, _): &Self::Input) -> Result<Answer, SolveError> {
+        Ok(decisions
+            .count_accepted(Cuboid([Interval::inclusive(1, 4000); 4]))
+            .into())
+    }
 }
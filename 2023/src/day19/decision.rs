@@ -0,0 +1,332 @@
+use std::{
+    collections::HashMap,
+    fmt::{self, Display},
+};
+
+use aoc_core::{Cuboid, Interval};
+
+use super::workflow::{Res, Rule, Workflow};
+
+const AXES: [char; 4] = ['x', 'm', 'a', 's'];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Next {
+    Accept,
+    Reject,
+    Test(usize),
+}
+
+/// Sends parts rated below `at` on `axis` to `then`, and the rest to `otherwise`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+struct Test {
+    axis: usize,
+    at: usize,
+    then: Next,
+    otherwise: Next,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Error {
+    UnknownWorkflow(String),
+    /// Workflows that forward parts around in a loop, the first one is repeated at the end.
+    Cycle(Vec<String>),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownWorkflow(name) => write!(f, "no workflow named {name}"),
+            Self::Cycle(names) => write!(f, "workflows forward in a loop: {}", names.join(" -> ")),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Tests shared between every path that reaches them. A test with the same outcome either way is
+/// never stored, its outcome is used instead.
+#[derive(Default)]
+struct Dag {
+    tests: Vec<Test>,
+    ids: HashMap<Test, usize>,
+}
+
+impl Dag {
+    fn test(&mut self, test: Test) -> Next {
+        if test.then == test.otherwise {
+            return test.then;
+        }
+
+        Next::Test(*self.ids.entry(test).or_insert_with(|| {
+            self.tests.push(test);
+            self.tests.len() - 1
+        }))
+    }
+
+    /// Rebuilds `next` from `tests` into this DAG, dropping the tests every part in `region`
+    /// passes or fails.
+    fn prune(
+        &mut self,
+        tests: &[Test],
+        next: Next,
+        region: Cuboid<usize, 4>,
+        pruned: &mut HashMap<(Next, Cuboid<usize, 4>), Next>,
+    ) -> Next {
+        let Next::Test(id) = next else {
+            return next;
+        };
+        if let Some(next) = pruned.get(&(next, region)) {
+            return *next;
+        }
+
+        let test = tests[id];
+        let (below, above) = region.split_at(test.axis, test.at);
+        let result = match (below.is_empty(), above.is_empty()) {
+            (false, true) => self.prune(tests, test.then, below, pruned),
+            (true, false) => self.prune(tests, test.otherwise, above, pruned),
+            _ => {
+                let then = self.prune(tests, test.then, below, pruned);
+                let otherwise = self.prune(tests, test.otherwise, above, pruned);
+                self.test(Test {
+                    then,
+                    otherwise,
+                    ..test
+                })
+            }
+        };
+
+        pruned.insert((next, region), result);
+        result
+    }
+}
+
+struct Compiler<'a> {
+    workflows: &'a HashMap<String, Workflow>,
+    dag: Dag,
+    compiled: HashMap<&'a str, Next>,
+    path: Vec<&'a str>,
+}
+
+impl<'a> Compiler<'a> {
+    fn workflow(&mut self, name: &'a str) -> Result<Next, Error> {
+        if let Some(next) = self.compiled.get(name) {
+            return Ok(*next);
+        }
+        if let Some(start) = self.path.iter().position(|other| *other == name) {
+            let mut cycle: Vec<String> = self.path[start..].iter().map(|n| n.to_string()).collect();
+            cycle.push(name.to_owned());
+            return Err(Error::Cycle(cycle));
+        }
+
+        let workflow = self
+            .workflows
+            .get(name)
+            .ok_or_else(|| Error::UnknownWorkflow(name.to_owned()))?;
+        self.path.push(name);
+
+        // parts that match no rule at all are rejected
+        let mut next = Next::Reject;
+        for (rule, res) in workflow.get_switch().iter().rev() {
+            let target = match res {
+                Res::Approve => Next::Accept,
+                Res::Reject => Next::Reject,
+                Res::Forward(id) => self.workflow(id)?,
+            };
+
+            next = match *rule {
+                Rule::Default => target,
+                Rule::LT(prop, num) => self.dag.test(Test {
+                    axis: prop.axis(),
+                    at: num,
+                    then: target,
+                    otherwise: next,
+                }),
+                Rule::GT(prop, num) => self.dag.test(Test {
+                    axis: prop.axis(),
+                    at: num + 1,
+                    then: next,
+                    otherwise: target,
+                }),
+            };
+        }
+
+        self.path.pop();
+        self.compiled.insert(name, next);
+        Ok(next)
+    }
+}
+
+/// The workflows compiled into one decision DAG, with forwards resolved and tests that can't
+/// change the outcome dropped.
+pub struct Decisions {
+    tests: Vec<Test>,
+    root: Next,
+}
+
+impl Decisions {
+    /// Ratings go from 1 to 4000, tests outside of that are dropped too.
+    pub fn compile(workflows: &HashMap<String, Workflow>, start: &str) -> Result<Self, Error> {
+        let mut compiler = Compiler {
+            workflows,
+            dag: Dag::default(),
+            compiled: HashMap::new(),
+            path: Vec::new(),
+        };
+        let root = compiler.workflow(start)?;
+
+        let mut dag = Dag::default();
+        let region = Cuboid([Interval::inclusive(1, 4000); 4]);
+        let root = dag.prune(&compiler.dag.tests, root, region, &mut HashMap::new());
+
+        Ok(Decisions {
+            tests: dag.tests,
+            root,
+        })
+    }
+
+    pub fn size(&self) -> usize {
+        self.tests.len()
+    }
+
+    pub fn accepts(&self, ratings: [usize; 4]) -> bool {
+        let mut next = self.root;
+        while let Next::Test(id) = next {
+            let test = &self.tests[id];
+            next = match ratings[test.axis] < test.at {
+                true => test.then,
+                false => test.otherwise,
+            };
+        }
+
+        next == Next::Accept
+    }
+
+    /// How many of the parts in `region` get accepted.
+    pub fn count_accepted(&self, region: Cuboid<usize, 4>) -> usize {
+        self.count(self.root, region)
+    }
+
+    fn count(&self, next: Next, region: Cuboid<usize, 4>) -> usize {
+        if region.is_empty() {
+            return 0;
+        }
+
+        match next {
+            Next::Accept => region.volume(),
+            Next::Reject => 0,
+            Next::Test(id) => {
+                let test = &self.tests[id];
+                let (below, above) = region.split_at(test.axis, test.at);
+                self.count(test.then, below) + self.count(test.otherwise, above)
+            }
+        }
+    }
+
+    /// Test ids in the order they're first reached, going through `then` before `otherwise`.
+    fn order(&self, next: Next, order: &mut Vec<usize>) {
+        if let Next::Test(id) = next {
+            if !order.contains(&id) {
+                order.push(id);
+                self.order(self.tests[id].then, order);
+                self.order(self.tests[id].otherwise, order);
+            }
+        }
+    }
+}
+
+/// One line per test, numbered from the root, as `#0: x < 1416 ? A : #1`.
+impl Display for Decisions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut order = Vec::new();
+        self.order(self.root, &mut order);
+
+        let label = |next: Next| match next {
+            Next::Accept => "A".to_owned(),
+            Next::Reject => "R".to_owned(),
+            Next::Test(id) => format!("#{}", order.iter().position(|o| *o == id).unwrap()),
+        };
+
+        if order.is_empty() {
+            return writeln!(f, "{}", label(self.root));
+        }
+        for (i, id) in order.iter().enumerate() {
+            let test = &self.tests[*id];
+            writeln!(
+                f,
+                "#{i}: {} < {} ? {} : {}",
+                AXES[test.axis],
+                test.at,
+                label(test.then),
+                label(test.otherwise)
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn compile(workflows: &str) -> Result<Decisions, Error> {
+        let workflows = workflows
+            .lines()
+            .map(Workflow::parse)
+            .map(|w| (w.copy_id(), w))
+            .collect();
+        Decisions::compile(&workflows, "in")
+    }
+
+    #[test]
+    fn drops_dead_and_redundant_tests() {
+        let decisions =
+            compile("in{x<10:a,m>5:b,R}\na{x>20:R,A}\nb{s<4001:c,R}\nc{a>2:A,A}").unwrap();
+
+        assert_eq!(decisions.size(), 2);
+        assert_eq!(
+            decisions.to_string(),
+            "#0: x < 10 ? A : #1\n#1: m < 6 ? R : A\n"
+        );
+        assert!(decisions.accepts([9, 1, 1, 1]));
+        assert!(!decisions.accepts([10, 5, 1, 1]));
+    }
+
+    #[test]
+    fn reports_bad_forwards() {
+        assert_eq!(
+            compile("in{x<10:a,R}\na{m<5:b,A}\nb{a<5:a,R}").err(),
+            Some(Error::Cycle(vec!["a".into(), "b".into(), "a".into()]))
+        );
+        assert_eq!(
+            compile("in{x<10:a,R}").err(),
+            Some(Error::UnknownWorkflow("a".into()))
+        );
+    }
+
+    #[test]
+    fn counts_accepted_parts_in_a_box() {
+        let example = include_str!("../../fixtures/day19.txt");
+        let decisions = compile(example.split("\n\n").next().unwrap()).unwrap();
+
+        let region = Cuboid([
+            Interval::new(1400, 1420),
+            Interval::new(830, 850),
+            Interval::new(2000, 2010),
+            Interval::new(1340, 1360),
+        ]);
+        let mut accepted = 0;
+        for x in 1400..1420 {
+            for m in 830..850 {
+                for a in 2000..2010 {
+                    for s in 1340..1360 {
+                        accepted += decisions.accepts([x, m, a, s]) as usize;
+                    }
+                }
+            }
+        }
+
+        assert!(accepted > 0);
+        assert_eq!(decisions.count_accepted(region), accepted);
+    }
+}
@@ -8,7 +8,7 @@ use regex::Regex;
 pub enum Rule {
     GT(Property, usize),
     LT(Property, usize),
-    DEFAULT,
+    Default,
 }
 
 #[derive(Hash, PartialEq, Eq, Debug, Clone, Copy)]
@@ -29,6 +29,15 @@ impl Property {
             _ => panic!(),
         }
     }
+
+    pub fn axis(self) -> usize {
+        match self {
+            Self::X => 0,
+            Self::M => 1,
+            Self::A => 2,
+            Self::S => 3,
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -71,8 +80,8 @@ impl Part {
         self.1
     }
 
-    fn get_prop(&self, prop: &Property) -> &usize {
-        &self.0[prop]
+    pub fn ratings(&self) -> [usize; 4] {
+        [Property::X, Property::M, Property::A, Property::S].map(|prop| self.0[&prop])
     }
 }
 
@@ -91,7 +100,7 @@ impl Workflow {
             .split(",")
             .map(|rule| match rule.find(':') {
                 None => (
-                    Rule::DEFAULT,
+                    Rule::Default,
                     match rule {
                         "A" => Res::Approve,
                         "R" => Res::Reject,
@@ -127,22 +136,6 @@ impl Workflow {
         self.id.clone()
     }
 
-    pub fn process(&self, part: &Part) -> &Res {
-        for (rule, result) in &self.switch {
-            let passed = match rule {
-                Rule::DEFAULT => true,
-                Rule::GT(prop, num) => part.get_prop(prop) > num,
-                Rule::LT(prop, num) => part.get_prop(prop) < num,
-            };
-
-            if passed {
-                return result;
-            }
-        }
-
-        panic!();
-    }
-
     pub fn get_switch(&self) -> &Vec<(Rule, Res)> {
         &self.switch
     }
@@ -1,5 +1,6 @@
 mod game;
 
+use aoc_core::{Answer, Solution, SolveError};
 use regex::Regex;
 
 use self::game::{Balls, Game};
@@ -7,63 +8,72 @@ use self::game::{Balls, Game};
 fn get_color(re: &Regex, part: &str) -> u8 {
     re.captures(part)
         .map(|cap| cap.get(1).unwrap().as_str())
-        .or(Some("0"))
-        .unwrap()
+        .unwrap_or("0")
         .parse::<u8>()
         .unwrap()
 }
 
-fn parse_input(input: &str) -> Vec<Game> {
-    let game_re = Regex::new(r"^Game (\d+):").unwrap();
-    let red = Regex::new(r"(\d+) red").unwrap();
-    let green = Regex::new(r"(\d+) green").unwrap();
-    let blue = Regex::new(r"(\d+) blue").unwrap();
+pub struct Day2;
 
-    input
-        .lines()
-        .map(|line| {
-            let id = game_re
-                .captures(line)
-                .unwrap()
-                .get(1)
-                .unwrap()
-                .as_str()
-                .parse::<u8>()
-                .unwrap();
+impl Solution for Day2 {
+    type Input = Vec<Game>;
 
-            let rev: Vec<Balls> = line
-                .split(";")
-                .map(|part| {
-                    (
-                        get_color(&red, part),
-                        get_color(&green, part),
-                        get_color(&blue, part),
-                    )
-                })
-                .collect();
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        let game_re = Regex::new(r"^Game (\d+):").unwrap();
+        let red = Regex::new(r"(\d+) red").unwrap();
+        let green = Regex::new(r"(\d+) green").unwrap();
+        let blue = Regex::new(r"(\d+) blue").unwrap();
 
-            Game::new(id, rev)
-        })
-        .collect()
-}
+        Ok(input
+            .lines()
+            .map(|line| {
+                let id = game_re
+                    .captures(line)
+                    .unwrap()
+                    .get(1)
+                    .unwrap()
+                    .as_str()
+                    .parse::<u8>()
+                    .unwrap();
 
-pub fn part1(input: &str) -> u32 {
-    let games = parse_input(input);
-    let max = (12, 13, 14);
+                let rev: Vec<Balls> = line
+                    .split(';')
+                    .map(|part| {
+                        (
+                            get_color(&red, part),
+                            get_color(&green, part),
+                            get_color(&blue, part),
+                        )
+                    })
+                    .collect();
 
-    games.iter().fold(0, |mut sum, game| {
-        if game.is_possible(max) {
-            sum += game.id() as u32
-        }
+                Game::new(id, rev)
+            })
+            .collect())
+    }
 
-        sum
-    })
-}
+    fn part1(games: &Self::Input) -> Result<Answer, SolveError> {
+        let max = (12, 13, 14);
+
+        Ok(games
+            .iter()
+            .fold(0, |mut sum, game| {
+                if game.is_possible(max) {
+                    sum += game.id() as u32
+                }
+
+                sum
+            })
+            .into())
+    }
 
-pub fn part2(input: &str) -> u32 {
-    let games = parse_input(input);
-    games.iter().fold(0, |sum, game| {
-        let min = game.min_possible();
-        sum + (min.0 as u32 * min.1 as u32 * min.2 as u32)
-    })
+    fn part2(games: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(games
+            .iter()
+            .fold(0, |sum, game| {
+                let min = game.min_possible();
+                sum + (min.0 as u32 * min.1 as u32 * min.2 as u32)
+            })
+            .into())
+    }
 }
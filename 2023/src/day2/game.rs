@@ -7,7 +7,7 @@ pub struct Game {
 
 impl Game {
     pub fn new(id: u8, revelations: Vec<Balls>) -> Self {
-        return Game { id, revelations };
+        Game { id, revelations }
     }
 
     pub fn is_possible(&self, max_balls: Balls) -> bool {
@@ -17,7 +17,7 @@ impl Game {
     }
 
     pub fn id(&self) -> u8 {
-        return self.id;
+        self.id
     }
 
     pub fn min_possible(&self) -> Balls {
@@ -0,0 +1,455 @@
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    fmt::Display,
+};
+
+use aoc_core::{
+    cycle::{self, Schedule},
+    search,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Pulse {
+    Low,
+    High,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Kind {
+    Button,
+    Broadcaster,
+    FlipFlop,
+    Conjunction,
+    /// Anything that is only sent to, like `rx`.
+    Output,
+}
+
+#[derive(Clone, Debug)]
+pub struct Module {
+    name: String,
+    kind: Kind,
+    inputs: Vec<usize>,
+    /// Where each output goes and which of the receiver's inputs it arrives on.
+    outputs: Vec<(usize, usize)>,
+}
+
+impl Module {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn kind(&self) -> Kind {
+        self.kind
+    }
+
+    pub fn inputs(&self) -> &[usize] {
+        &self.inputs
+    }
+
+    pub fn outputs(&self) -> impl Iterator<Item = usize> + '_ {
+        self.outputs.iter().map(|(to, _)| *to)
+    }
+}
+
+/// A pulse travelling from one module to another.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Event {
+    pub from: usize,
+    pub to: usize,
+    pub pulse: Pulse,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Error {
+    UnknownModule(String),
+    /// The circuit repeats itself without ever sending the pulse.
+    Never,
+    /// The structure around the target doesn't split into counters that can be run on their
+    /// own.
+    NotDecomposable(String),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnknownModule(name) => write!(f, "there is no module called {name}"),
+            Self::Never => write!(f, "the circuit loops without ever sending that pulse"),
+            Self::NotDecomposable(reason) => {
+                write!(
+                    f,
+                    "circuit doesn't split into independent counters: {reason}"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Flip-flops that are on and the last pulse each conjunction input got, in module order.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+struct State {
+    on: Vec<bool>,
+    memory: Vec<Vec<bool>>,
+}
+
+#[derive(Clone, Debug)]
+pub struct Circuit {
+    modules: Vec<Module>,
+    ids: HashMap<String, usize>,
+    state: State,
+    presses: usize,
+    received: Vec<[usize; 2]>,
+}
+
+impl Circuit {
+    pub const BUTTON: usize = 0;
+
+    pub fn parse(input: &str) -> Self {
+        let mut names = vec![String::from("button")];
+        let mut ids = HashMap::from([(String::from("button"), Self::BUTTON)]);
+        let mut id = |name: &str| {
+            *ids.entry(name.to_owned()).or_insert_with(|| {
+                names.push(name.to_owned());
+                names.len() - 1
+            })
+        };
+
+        let mut kinds = HashMap::from([(Self::BUTTON, Kind::Button)]);
+        let mut edges = vec![(Self::BUTTON, id("broadcaster"))];
+
+        for line in input.lines() {
+            let (module, outputs) = line.split_once(" -> ").unwrap();
+            let (kind, name) = match module.split_at(1) {
+                ("%", name) => (Kind::FlipFlop, name),
+                ("&", name) => (Kind::Conjunction, name),
+                _ => (Kind::Broadcaster, module),
+            };
+
+            let from = id(name);
+            kinds.insert(from, kind);
+            edges.extend(outputs.split(", ").map(|to| (from, id(to))));
+        }
+
+        let mut modules: Vec<Module> = names
+            .into_iter()
+            .enumerate()
+            .map(|(id, name)| Module {
+                name,
+                kind: kinds.get(&id).copied().unwrap_or(Kind::Output),
+                inputs: Vec::new(),
+                outputs: Vec::new(),
+            })
+            .collect();
+
+        for (from, to) in edges {
+            let slot = modules[to].inputs.len();
+            modules[to].inputs.push(from);
+            modules[from].outputs.push((to, slot));
+        }
+
+        Self::new(modules, ids)
+    }
+
+    fn new(modules: Vec<Module>, ids: HashMap<String, usize>) -> Self {
+        let state = State {
+            on: vec![false; modules.len()],
+            memory: modules
+                .iter()
+                .map(|module| vec![false; module.inputs.len()])
+                .collect(),
+        };
+
+        Circuit {
+            received: vec![[0; 2]; modules.len()],
+            modules,
+            ids,
+            state,
+            presses: 0,
+        }
+    }
+
+    pub fn modules(&self) -> &[Module] {
+        &self.modules
+    }
+
+    pub fn id(&self, name: &str) -> Result<usize, Error> {
+        self.ids
+            .get(name)
+            .copied()
+            .ok_or_else(|| Error::UnknownModule(name.to_owned()))
+    }
+
+    pub fn presses(&self) -> usize {
+        self.presses
+    }
+
+    /// How many pulses of each kind `id` got since the circuit was built.
+    pub fn received(&self, id: usize, pulse: Pulse) -> usize {
+        self.received[id][pulse as usize]
+    }
+
+    pub fn is_on(&self, id: usize) -> bool {
+        self.state.on[id]
+    }
+
+    /// Presses the button and handles pulses until the circuit settles, passing each one to
+    /// `on_pulse` in the order they are handled. Returns how many low and high pulses were sent.
+    pub fn press_with(&mut self, mut on_pulse: impl FnMut(&Event)) -> [usize; 2] {
+        self.presses += 1;
+
+        let mut sent = [0; 2];
+        let broadcaster = self.modules[Self::BUTTON].outputs[0];
+        let mut queue = VecDeque::from([(Self::BUTTON, broadcaster, Pulse::Low)]);
+
+        while let Some((from, (to, slot), pulse)) = queue.pop_front() {
+            sent[pulse as usize] += 1;
+            self.received[to][pulse as usize] += 1;
+            on_pulse(&Event { from, to, pulse });
+
+            let out = match (self.modules[to].kind, pulse) {
+                (Kind::Broadcaster, _) => pulse,
+                (Kind::FlipFlop, Pulse::Low) => {
+                    self.state.on[to] = !self.state.on[to];
+                    match self.state.on[to] {
+                        true => Pulse::High,
+                        false => Pulse::Low,
+                    }
+                }
+                (Kind::Conjunction, _) => {
+                    let memory = &mut self.state.memory[to];
+                    memory[slot] = pulse == Pulse::High;
+                    match memory.iter().all(|high| *high) {
+                        true => Pulse::Low,
+                        false => Pulse::High,
+                    }
+                }
+                _ => continue,
+            };
+
+            for output in &self.modules[to].outputs {
+                queue.push_back((to, *output, out));
+            }
+        }
+
+        sent
+    }
+
+    pub fn press(&mut self) -> [usize; 2] {
+        self.press_with(|_| {})
+    }
+
+    /// Modules reachable from `start` without going through the button.
+    fn downstream(&self, start: usize) -> HashSet<usize> {
+        search::bfs(
+            [start],
+            |id| self.modules[*id].outputs().collect::<Vec<_>>(),
+            |_| false,
+        )
+        .into_distances()
+        .into_keys()
+        .collect()
+    }
+
+    /// A fresh copy of just the button, the broadcaster and `members`, with the broadcaster only
+    /// sending to members and every module the members send to kept as an output.
+    fn subcircuit(&self, members: &HashSet<usize>) -> Self {
+        let broadcaster = self.modules[Self::BUTTON].outputs[0].0;
+        let kept = |id: &usize| *id == Self::BUTTON || *id == broadcaster || members.contains(id);
+
+        let modules = self
+            .modules
+            .iter()
+            .enumerate()
+            .map(|(id, module)| match kept(&id) {
+                true => Module {
+                    name: module.name.clone(),
+                    kind: module.kind,
+                    inputs: module.inputs.clone(),
+                    outputs: module
+                        .outputs
+                        .iter()
+                        .filter(|(to, _)| id != broadcaster || members.contains(to))
+                        .copied()
+                        .collect(),
+                },
+                false => Module {
+                    name: module.name.clone(),
+                    kind: Kind::Output,
+                    inputs: module.inputs.clone(),
+                    outputs: Vec::new(),
+                },
+            })
+            .collect();
+
+        Self::new(modules, self.ids.clone())
+    }
+
+    /// The presses `to` gets `pulse` on (from `from` if given), counting from 0 and going on
+    /// until the circuit repeats.
+    fn schedule(mut self, from: Option<usize>, to: usize, pulse: Pulse) -> Schedule {
+        let mut hits = Vec::new();
+        let cycle = cycle::find_cycle(
+            &mut self,
+            |circuit| {
+                let mut hit = false;
+                circuit.press_with(|event| {
+                    hit |= event.to == to
+                        && event.pulse == pulse
+                        && from.is_none_or(|from| event.from == from);
+                });
+                hits.push(hit);
+            },
+            |circuit| circuit.state.clone(),
+        );
+
+        let times = hits.into_iter().enumerate().filter(|(_, hit)| *hit);
+        Schedule::new(cycle, times.map(|(n, _)| n))
+    }
+
+    /// The first press on which `target` receives `pulse`.
+    ///
+    /// The broadcaster's outputs each start a counter, the modules only reachable from it.
+    /// Counters are run on their own until they repeat. A target inside one counter is answered
+    /// from that counter alone. A target outside of them has to get its low pulse from a
+    /// conjunction fed by one module from each counter, and is reached on the first press all of
+    /// them send that conjunction a high pulse.
+    pub fn first_press(&self, target: &str, pulse: Pulse) -> Result<usize, Error> {
+        let target = self.id(target)?;
+        let broadcaster = self.modules[Self::BUTTON].outputs[0].0;
+
+        let starts: Vec<usize> = self.modules[broadcaster].outputs().collect();
+        let reach: Vec<HashSet<usize>> = starts.iter().map(|id| self.downstream(*id)).collect();
+        let owner = |id: usize| {
+            let mut owners = (0..starts.len()).filter(|i| reach[*i].contains(&id));
+            match (owners.next(), owners.next()) {
+                (Some(owner), None) => Some(owner),
+                _ => None,
+            }
+        };
+
+        let counters: Vec<HashSet<usize>> = (0..starts.len())
+            .map(|i| {
+                reach[i]
+                    .iter()
+                    .copied()
+                    .filter(|id| owner(*id) == Some(i))
+                    .collect()
+            })
+            .collect();
+
+        let counter = |i: usize| -> Result<Self, Error> {
+            for id in &counters[i] {
+                let module = &self.modules[*id];
+                if let Some(other) = module
+                    .inputs
+                    .iter()
+                    .find(|input| **input != broadcaster && !counters[i].contains(input))
+                {
+                    return Err(Error::NotDecomposable(format!(
+                        "{} listens to {} from outside its counter",
+                        module.name, self.modules[*other].name
+                    )));
+                }
+            }
+
+            Ok(self.subcircuit(&counters[i]))
+        };
+
+        let schedules = if let Some(i) = owner(target) {
+            vec![counter(i)?.schedule(None, target, pulse)]
+        } else {
+            let feeder = match self.modules[target].inputs[..] {
+                [feeder] if self.modules[feeder].kind == Kind::Conjunction => feeder,
+                _ => {
+                    return Err(Error::NotDecomposable(format!(
+                        "{} isn't fed by a single conjunction",
+                        self.modules[target].name
+                    )))
+                }
+            };
+            if pulse != Pulse::Low {
+                return Err(Error::NotDecomposable(String::from(
+                    "only low pulses from a conjunction can be lined up",
+                )));
+            }
+
+            let mut used = HashSet::new();
+            self.modules[feeder]
+                .inputs
+                .iter()
+                .map(|input| match owner(*input) {
+                    Some(i) if used.insert(i) => {
+                        Ok(counter(i)?.schedule(Some(*input), feeder, Pulse::High))
+                    }
+                    _ => Err(Error::NotDecomposable(format!(
+                        "{} doesn't belong to a counter of its own",
+                        self.modules[*input].name
+                    ))),
+                })
+                .collect::<Result<_, _>>()?
+        };
+
+        cycle::first_common(&schedules)
+            .map(|n| n + 1)
+            .ok_or(Error::Never)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn brute_force(circuit: &Circuit, target: &str, pulse: Pulse) -> usize {
+        let mut circuit = circuit.clone();
+        let target = circuit.id(target).unwrap();
+
+        loop {
+            let mut hit = false;
+            circuit.press_with(|event| hit |= event.to == target && event.pulse == pulse);
+            if hit {
+                return circuit.presses();
+            }
+        }
+    }
+
+    #[test]
+    fn counts_pulses() {
+        let mut circuit = Circuit::parse(include_str!("../../fixtures/day20-2.txt"));
+
+        assert_eq!(circuit.press(), [4, 4]);
+        assert_eq!(circuit.press(), [4, 2]);
+        let output = circuit.id("output").unwrap();
+        assert_eq!(circuit.received(output, Pulse::Low), 1);
+        assert_eq!(circuit.received(output, Pulse::High), 2);
+    }
+
+    #[test]
+    fn finds_first_press() {
+        let circuit = Circuit::parse(include_str!("../../fixtures/day20-2.txt"));
+        for (target, pulse) in [("output", Pulse::Low), ("b", Pulse::High)] {
+            assert_eq!(
+                circuit.first_press(target, pulse),
+                Ok(brute_force(&circuit, target, pulse))
+            );
+        }
+        assert_eq!(circuit.first_press("a", Pulse::High), Err(Error::Never));
+        assert!(circuit.first_press("nope", Pulse::Low).is_err());
+
+        let circuit = Circuit::parse(include_str!("../../fixtures/day20-counters.txt"));
+        assert_eq!(
+            circuit.first_press("rx", Pulse::Low),
+            Ok(brute_force(&circuit, "rx", Pulse::Low))
+        );
+    }
+
+    #[test]
+    fn rejects_shared_modules() {
+        let circuit = Circuit::parse(include_str!("../../fixtures/day20.txt"));
+
+        assert!(matches!(
+            circuit.first_press("inv", Pulse::Low),
+            Err(Error::NotDecomposable(_))
+        ));
+    }
+}
@@ -0,0 +1,191 @@
+use std::io::{self, Write};
+
+use super::circuit::{Circuit, Event, Kind, Pulse};
+
+/// Every pulse sent during a number of button presses, with the press it was sent on counting
+/// from 1.
+pub struct Trace {
+    events: Vec<(usize, Event)>,
+    presses: usize,
+    initial: Vec<bool>,
+}
+
+impl Trace {
+    pub fn record(circuit: &mut Circuit, presses: usize) -> Self {
+        let initial = (0..circuit.modules().len())
+            .map(|id| circuit.is_on(id))
+            .collect();
+        let mut events = Vec::new();
+
+        for press in 1..=presses {
+            circuit.press_with(|event| events.push((press, *event)));
+        }
+
+        Trace {
+            events,
+            presses,
+            initial,
+        }
+    }
+
+    pub fn events(&self) -> &[(usize, Event)] {
+        &self.events
+    }
+
+    /// Whether each module is on after every press, starting with before the first one.
+    fn histories(&self, circuit: &Circuit) -> Vec<Vec<bool>> {
+        let mut toggles = vec![vec![false; self.presses + 1]; self.initial.len()];
+        for (press, event) in &self.events {
+            if event.pulse == Pulse::Low && circuit.modules()[event.to].kind() == Kind::FlipFlop {
+                toggles[event.to][*press] ^= true;
+            }
+        }
+
+        toggles
+            .into_iter()
+            .zip(&self.initial)
+            .map(|(toggles, initial)| {
+                toggles
+                    .into_iter()
+                    .scan(*initial, |on, toggle| {
+                        *on ^= toggle;
+                        Some(*on)
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Writes a Value Change Dump with a wire per module holding the last pulse it sent, which is
+    /// unknown until it sends one, and the press count. Every pulse is one time step.
+    pub fn write_vcd(&self, circuit: &Circuit, out: &mut impl Write) -> io::Result<()> {
+        let modules = circuit.modules();
+        let code = |id: usize| {
+            // identifiers are printable ascii, written in base 94
+            let (mut id, mut code) = (id, String::new());
+            loop {
+                code.push((b'!' + (id % 94) as u8) as char);
+                id /= 94;
+                if id == 0 {
+                    return code;
+                }
+            }
+        };
+        let press_code = code(modules.len());
+
+        writeln!(out, "$timescale 1ns $end")?;
+        writeln!(out, "$scope module circuit $end")?;
+        for (id, module) in modules.iter().enumerate() {
+            writeln!(out, "$var wire 1 {} {} $end", code(id), module.name())?;
+        }
+        writeln!(out, "$var integer 32 {press_code} press $end")?;
+        writeln!(out, "$upscope $end")?;
+        writeln!(out, "$enddefinitions $end")?;
+
+        writeln!(out, "#0")?;
+        writeln!(out, "$dumpvars")?;
+        for id in 0..modules.len() {
+            writeln!(out, "x{}", code(id))?;
+        }
+        writeln!(out, "b0 {press_code}")?;
+        writeln!(out, "$end")?;
+
+        let mut levels = vec![None; modules.len()];
+        let mut press = 0;
+        for (time, (event_press, event)) in self.events.iter().enumerate() {
+            let level = Some(event.pulse);
+            if *event_press == press && levels[event.from] == level {
+                continue;
+            }
+
+            writeln!(out, "#{}", time + 1)?;
+            if *event_press != press {
+                press = *event_press;
+                writeln!(out, "b{press:b} {press_code}")?;
+            }
+            if levels[event.from] != level {
+                levels[event.from] = level;
+                let value = if event.pulse == Pulse::High { 1 } else { 0 };
+                writeln!(out, "{value}{}", code(event.from))?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Writes when each flip-flop is first on and how many presses its on/off pattern takes to
+    /// repeat, if it repeats at least once within the trace.
+    pub fn write_flip_flop_periods(
+        &self,
+        circuit: &Circuit,
+        out: &mut impl Write,
+    ) -> io::Result<()> {
+        let histories = self.histories(circuit);
+        for (module, history) in circuit.modules().iter().zip(histories) {
+            if module.kind() != Kind::FlipFlop {
+                continue;
+            }
+
+            let first = history.iter().position(|on| *on);
+            let period = (1..=self.presses / 2).find(|period| {
+                (0..history.len() - period).all(|press| history[press] == history[press + period])
+            });
+
+            write!(out, "{:<8}", module.name())?;
+            match first {
+                Some(first) => write!(out, "first on after press {first:<6}")?,
+                None => write!(out, "never on{:21}", "")?,
+            }
+            match period {
+                Some(period) => writeln!(out, "period {period}")?,
+                None => writeln!(out, "no period within {} presses", self.presses)?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_counter_periods() {
+        let mut circuit = Circuit::parse(include_str!("../../fixtures/day20-counters.txt"));
+        let trace = Trace::record(&mut circuit, 40);
+
+        let mut summary = Vec::new();
+        trace
+            .write_flip_flop_periods(&circuit, &mut summary)
+            .unwrap();
+        let summary = String::from_utf8(summary).unwrap();
+        let lines: Vec<&str> = summary.lines().collect();
+
+        // a counts to 19 and starts over, the lowest bit flips every press until then
+        assert_eq!(lines[0], "a0      first on after press 1     period 19");
+        assert_eq!(lines[6], "a4      first on after press 16    period 19");
+        assert_eq!(
+            lines[14],
+            "c4      first on after press 16    no period within 40 presses"
+        );
+    }
+
+    #[test]
+    fn writes_value_changes() {
+        let mut circuit = Circuit::parse(include_str!("../../fixtures/day20-2.txt"));
+        let trace = Trace::record(&mut circuit, 2);
+        assert_eq!(trace.events().len(), 14);
+
+        let mut vcd = Vec::new();
+        trace.write_vcd(&circuit, &mut vcd).unwrap();
+        let vcd = String::from_utf8(vcd).unwrap();
+
+        assert!(vcd.contains("$var wire 1 \" broadcaster $end"));
+        assert!(vcd.contains("$enddefinitions $end\n#0\n$dumpvars\nx!\n"));
+        assert!(vcd.contains("$var integer 32 ( press $end"));
+        assert!(vcd.contains("#1\nb1 (\n0!\n#2\n0\"\n#3\n1#\n#5\n0$\n"));
+        // the second press only changes a, inv and con
+        assert!(vcd.ends_with("#9\nb10 (\n#11\n0#\n#13\n1$\n#14\n1%\n"));
+    }
+}
@@ -1,127 +1,232 @@
-use std::collections::{HashMap, HashSet, LinkedList};
+use std::collections::HashMap;
 
-fn parse_input(input: &str) -> (usize, (usize, usize), HashSet<(usize, usize)>) {
-    let mut start = (0, 0);
-    let mut rocks = HashSet::new();
+use aoc_core::{search, Answer, Grid, Pos, Solution, SolveError};
 
-    input.lines().enumerate().for_each(|(row, line)| {
-        line.chars().enumerate().for_each(|(col, c)| match c {
-            'S' => start = (row, col),
-            '#' => {
-                rocks.insert((row, col));
-            }
-            _ => {}
-        })
-    });
+/// How far the tile distances get computed around the start tile. The pattern has to settle
+/// one tile before the edge, so the last one can be checked against.
+const RADIUS: usize = 5;
 
-    // input is square
-    (input.lines().count(), start, rocks)
-}
+/// How many copies of a plot `distance` steps away get reached in exactly `steps` steps, when the
+/// copies repeat every `size` steps further out. Along an axis there's one copy per tile, in a
+/// quadrant there are `k + 1` copies `k` tiles further out.
+fn repeats(distance: usize, steps: usize, size: usize, quadrant: bool) -> usize {
+    if distance > steps {
+        return 0;
+    }
 
-pub fn part1(input: &str) -> usize {
-    let (size, start, rocks) = parse_input(input);
+    let (first, stride) = match (size % 2, (steps - distance) % 2) {
+        (0, 0) => (1, 1),
+        (0, _) => return 0,
+        (_, 1) => (1, 2),
+        _ => (2, 2),
+    };
 
-    let distances = find_distances(size, start, &rocks);
+    let max = (steps - distance) / size;
+    if max < first {
+        return 0;
+    }
 
-    // 64 steps
-    distances
-        .iter()
-        .filter(|(_, distance)| {
-            let d = **distance;
-            d % 2 == 0 && d <= 64
-        })
-        .count()
+    let count = (max - first) / stride + 1;
+    match quadrant {
+        true => count * (first + 1) + stride * count * (count - 1) / 2,
+        false => count,
+    }
 }
 
-pub fn part2(input: &str) -> usize {
-    let (size, start, rocks) = parse_input(input);
-    let distances = find_distances(size, start, &rocks);
+pub struct Garden {
+    start: Pos,
+    rocks: Grid<bool>,
+}
 
-    let step_target = 26501365;
-    let half_size = (size - 1) / 2;
-    let rings = (step_target - half_size) / size;
-    let even_ring_count = rings % 2 == 0;
+impl Garden {
+    fn is_rock(&self, (row, col): (isize, isize)) -> bool {
+        let size = self.rocks.height() as isize;
+        self.rocks[(row.rem_euclid(size) as usize, col.rem_euclid(size) as usize)]
+    }
 
-    let (odds, evens) = match even_ring_count {
-        true => ((rings + 1).pow(2), rings.pow(2)),
-        false => (rings.pow(2), (rings + 1).pow(2)),
-    };
+    /// Step counts from the start to every plot of the infinite map that `within` accepts,
+    /// walking only through those.
+    fn walk(&self, within: impl Fn((isize, isize)) -> bool) -> HashMap<(isize, isize), usize> {
+        let start = (self.start.0 as isize, self.start.1 as isize);
+        let within = &within;
+
+        search::bfs(
+            [start],
+            |&(row, col)| {
+                [
+                    (row - 1, col),
+                    (row + 1, col),
+                    (row, col - 1),
+                    (row, col + 1),
+                ]
+                .into_iter()
+                .filter(move |next| within(*next) && !self.is_rock(*next))
+            },
+            |_| false,
+        )
+        .into_distances()
+    }
 
-    let extra_corner_squares = rings;
-    let cutoff_corner_squares = rings + 1;
+    /// Step counts to every plot in the tiles up to `RADIUS` away from the start tile, indexed by
+    /// tile and then by plot.
+    fn tile_distances(&self) -> Vec<Vec<Option<usize>>> {
+        let size = self.rocks.height() as isize;
+        let (tiles, radius) = (2 * RADIUS as isize + 1, RADIUS as isize);
+        let area = -radius * size..(radius + 1) * size;
+
+        let distances = self.walk(|(row, col)| area.contains(&row) && area.contains(&col));
+
+        (0..tiles * tiles)
+            .map(|tile| {
+                let (tile_row, tile_col) = (
+                    (tile / tiles - radius) * size,
+                    (tile % tiles - radius) * size,
+                );
+                self.rocks
+                    .positions()
+                    .map(|(row, col)| {
+                        let pos = (tile_row + row as isize, tile_col + col as isize);
+                        distances.get(&pos).copied()
+                    })
+                    .collect()
+            })
+            .collect()
+    }
 
-    let mut even_fill = 0;
-    let mut odd_fill = 0;
-    let mut extra_corner_fill = 0;
-    let mut cutoff_corner_fill = 0;
+    /// Plots reachable in exactly `steps` steps on the infinitely tiled map. Tiles far enough out
+    /// are one tile size further away than their inner neighbor, so the counts there follow from
+    /// the outermost explicitly walked tiles. `None` if the distances haven't settled into that
+    /// pattern before the edge of the walked area.
+    pub fn reachable(&self, steps: usize) -> Option<usize> {
+        let size = self.rocks.height();
+        let distances = self.tile_distances();
+        let tile = |row: isize, col: isize| {
+            let tiles = 2 * RADIUS as isize + 1;
+            &distances[((row + RADIUS as isize) * tiles + col + RADIUS as isize) as usize]
+        };
+
+        let settled = |radius: isize| {
+            (-radius..=radius).all(|t| {
+                [(1, 0), (-1, 0), (0, 1), (0, -1_isize)]
+                    .into_iter()
+                    .all(|(dr, dc)| {
+                        let (row, col) = (t * dc.abs() + radius * dr, t * dr.abs() + radius * dc);
+                        let (inner, outer) = (tile(row, col), tile(row + dr, col + dc));
+                        inner
+                            .iter()
+                            .zip(outer)
+                            .all(|(inner, outer)| inner.map(|distance| distance + size) == *outer)
+                    })
+            })
+        };
+
+        let radius = (1..RADIUS as isize).find(|radius| settled(*radius))?;
+
+        let mut total = 0;
+        for row in -radius..=radius {
+            for col in -radius..=radius {
+                let edge = match (row.abs() == radius, col.abs() == radius) {
+                    (true, true) => Some(true),
+                    (true, false) | (false, true) => Some(false),
+                    _ => None,
+                };
+
+                for distance in tile(row, col).iter().flatten() {
+                    if *distance <= steps && (steps - distance).is_multiple_of(2) {
+                        total += 1;
+                    }
+                    if let Some(quadrant) = edge {
+                        total += repeats(*distance, steps, size, quadrant);
+                    }
+                }
+            }
+        }
 
-    distances.iter().for_each(|(_, distance)| {
-        let d = *distance;
+        Some(total)
+    }
 
-        match d % 2 == 0 {
-            true => {
-                even_fill += 1;
-                if d <= half_size {
-                    return;
-                }
+    /// Walks the infinite map plot by plot, only usable for small step counts.
+    pub fn reachable_brute(&self, steps: usize) -> usize {
+        let start = (self.start.0 as isize, self.start.1 as isize);
 
-                match even_ring_count {
-                    true => extra_corner_fill += 1,
-                    false => cutoff_corner_fill += 1,
-                }
-            }
-            false => {
-                odd_fill += 1;
-                if d <= half_size {
-                    return;
-                }
+        // nothing further than `steps` as the crow flies can be reached in time
+        self.walk(|(row, col)| row.abs_diff(start.0) + col.abs_diff(start.1) <= steps)
+            .values()
+            .filter(|distance| **distance <= steps && (steps - **distance).is_multiple_of(2))
+            .count()
+    }
+}
 
-                match even_ring_count {
-                    true => cutoff_corner_fill += 1,
-                    false => extra_corner_fill += 1,
-                }
-            }
-        }
-    });
+pub struct Day21;
 
-    odds * odd_fill + evens * even_fill + extra_corner_squares * extra_corner_fill
-        - cutoff_corner_squares * cutoff_corner_fill
+fn unsettled() -> SolveError {
+    SolveError::Failed("the garden never settles into a repeating pattern".to_owned())
 }
 
-fn neighbors(size: usize, (row, col): (usize, usize)) -> Vec<(usize, usize)> {
-    [(1, 0), (0, 1), (-1, 0), (0, -1)]
-        .into_iter()
-        .map(|(dr, dc)| (row as isize + dr, col as isize + dc))
-        .filter(|(r, c)| *r >= 0 && *c >= 0 && *r < size as isize && *c < size as isize)
-        .map(|(r, c)| (r as usize, c as usize))
-        .collect()
+impl Solution for Day21 {
+    type Input = Garden;
+
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        let map = Grid::parse(input, |c| c);
+        let start = map
+            .position(|c| *c == 'S')
+            .ok_or_else(|| SolveError::Failed("the map has no starting plot".to_owned()))?;
+
+        // the extrapolation relies on tiles being square
+        if map.width() != map.height() {
+            return Err(SolveError::Failed(format!(
+                "the map is {}x{}, not square",
+                map.width(),
+                map.height()
+            )));
+        }
+
+        Ok(Garden {
+            start,
+            rocks: map.map(|c| *c == '#'),
+        })
+    }
+
+    fn part1(garden: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(garden.reachable_brute(64).into())
+    }
+
+    fn part2(garden: &Self::Input) -> Result<Answer, SolveError> {
+        garden
+            .reachable(26501365)
+            .map(Answer::from)
+            .ok_or_else(unsettled)
+    }
 }
 
-fn find_distances(
-    size: usize,
-    start: (usize, usize),
-    rocks: &HashSet<(usize, usize)>,
-) -> HashMap<(usize, usize), usize> {
-    let mut distances = HashMap::new();
-    distances.insert(start, 0);
-
-    let mut queue = LinkedList::new();
-    queue.push_back((start, 0));
-
-    while !queue.is_empty() {
-        let (pos, distance) = queue.pop_front().unwrap();
-
-        let valid_neightbors: Vec<(usize, usize)> = neighbors(size, pos)
-            .into_iter()
-            .filter(|n| !rocks.contains(n))
-            .filter(|n| !distances.contains_key(n))
-            .collect();
-
-        for n in valid_neightbors {
-            distances.insert(n, distance + 1);
-            queue.push_back((n, distance + 1));
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../fixtures/day21.txt");
+
+    #[test]
+    fn counts_plots_on_the_infinite_map() {
+        let garden = Day21::parse(EXAMPLE).unwrap();
+
+        for (steps, plots) in [(6, 16), (10, 50), (50, 1594), (100, 6536), (500, 167004)] {
+            assert_eq!(garden.reachable(steps), Some(plots));
         }
+        assert_eq!(garden.reachable(5000), Some(16733044));
     }
 
-    distances
+    #[test]
+    fn reports_unusable_maps() {
+        assert!(Day21::parse("...\n...\n...").is_err());
+        assert!(Day21::parse(".S.\n...").is_err());
+    }
+
+    #[test]
+    fn matches_brute_force() {
+        let garden = Day21::parse(EXAMPLE).unwrap();
+
+        for steps in [0, 1, 7, 33, 64, 65, 131] {
+            assert_eq!(garden.reachable(steps), Some(garden.reachable_brute(steps)));
+        }
+    }
 }
@@ -3,44 +3,12 @@ use std::{
     collections::{HashMap, HashSet},
 };
 
+use aoc_core::{Answer, Solution, SolveError};
 use itertools::Itertools;
 
 type Coord = (i16, i16, i16);
-type Brick = (u16, (Coord, Coord));
 
-fn parse_input(input: &str) -> Vec<Brick> {
-    let mut id = 0;
-    input
-        .lines()
-        .map(|line| {
-            let coords = line
-                .split("~")
-                .map(|part| {
-                    part.split(",")
-                        .map(|c| c.parse::<i16>().unwrap())
-                        .collect_tuple::<Coord>()
-                        .unwrap()
-                })
-                .sorted_by(|a: &Coord, b: &Coord| match a.2.cmp(&b.2) {
-                    Ordering::Equal => match a.1.cmp(&b.1) {
-                        Ordering::Equal => a.0.cmp(&a.0),
-                        o => o,
-                    },
-                    o => o,
-                })
-                .collect_tuple()
-                .unwrap();
-            let brick = (id, coords);
-            id += 1;
-
-            brick
-        })
-        .sorted_by(|a: &Brick, b: &Brick| match a.1 .0 .2.cmp(&b.1 .0 .2) {
-            Ordering::Equal => a.1 .1 .2.cmp(&b.1 .1 .2),
-            ord => ord,
-        })
-        .collect()
-}
+type Brick = (u16, (Coord, Coord));
 
 fn does_intersect(
     (_, ((ax1, ay1, _), (ax2, ay2, _))): &Brick,
@@ -63,19 +31,19 @@ fn fall(z_map: &HashMap<i16, Vec<Brick>>, brick: &mut Brick) -> Vec<u16> {
                 .map(|(id, _)| *id)
                 .collect();
 
-            if bricks_under.len() > 0 {
+            if !bricks_under.is_empty() {
                 return bricks_under;
             }
         }
 
         let (_, ((_, _, z1), (_, _, z2))) = brick;
-        *z1 = *z1 - 1;
-        *z2 = *z2 - 1;
+        *z1 -= 1;
+        *z2 -= 1;
     }
 }
 
-fn build_maps(bricks: &Vec<Brick>) -> (HashMap<u16, Vec<u16>>, HashMap<u16, Vec<u16>>) {
-    let mut bricks = bricks.clone();
+fn build_maps(bricks: &[Brick]) -> (HashMap<u16, Vec<u16>>, HashMap<u16, Vec<u16>>) {
+    let mut bricks = bricks.to_vec();
     let mut z_end_map: HashMap<i16, Vec<Brick>> = HashMap::new();
     let mut below_map: HashMap<u16, Vec<u16>> = HashMap::new();
     let mut above_map: HashMap<u16, Vec<u16>> = HashMap::new();
@@ -120,7 +88,7 @@ fn count(
 
                 supports == 0
             })
-            .map(|id| *id)
+            .copied()
             .collect();
 
         fallen.extend(no_longer_supported.iter());
@@ -131,44 +99,80 @@ fn count(
     }
 }
 
-pub fn part1(input: &str) -> usize {
-    let bricks = parse_input(input);
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Input = Vec<Brick>;
+
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        let mut id = 0;
+        Ok(input
+            .lines()
+            .map(|line| {
+                let coords = line
+                    .split("~")
+                    .map(|part| {
+                        part.split(",")
+                            .map(|c| c.parse::<i16>().unwrap())
+                            .collect_tuple::<Coord>()
+                            .unwrap()
+                    })
+                    .sorted_by(|a: &Coord, b: &Coord| match a.2.cmp(&b.2) {
+                        Ordering::Equal => match a.1.cmp(&b.1) {
+                            Ordering::Equal => a.0.cmp(&a.0),
+                            o => o,
+                        },
+                        o => o,
+                    })
+                    .collect_tuple()
+                    .unwrap();
+                let brick = (id, coords);
+                id += 1;
+
+                brick
+            })
+            .sorted_by(|a: &Brick, b: &Brick| match a.1 .0 .2.cmp(&b.1 .0 .2) {
+                Ordering::Equal => a.1 .1 .2.cmp(&b.1 .1 .2),
+                ord => ord,
+            })
+            .collect())
+    }
 
-    let mut safe = 0;
+    fn part1(bricks: &Self::Input) -> Result<Answer, SolveError> {
+        let mut safe = 0;
 
-    let (below_map, above_map) = build_maps(&bricks);
+        let (below_map, above_map) = build_maps(bricks);
 
-    bricks.iter().for_each(|(id, _)| {
-        if let Some(bricks) = above_map.get(&id) {
-            let count = bricks
-                .iter()
-                .filter(|above_brick| below_map.get(above_brick).unwrap().len() < 2)
-                .count();
+        bricks.iter().for_each(|(id, _)| {
+            if let Some(bricks) = above_map.get(id) {
+                let count = bricks
+                    .iter()
+                    .filter(|above_brick| below_map.get(above_brick).unwrap().len() < 2)
+                    .count();
 
-            if count == 0 {
+                if count == 0 {
+                    safe += 1;
+                }
+            } else {
                 safe += 1;
             }
-        } else {
-            safe += 1;
-        }
-    });
-
-    safe
-}
+        });
 
-pub fn part2(input: &str) -> usize {
-    let bricks = parse_input(input);
+        Ok(safe.into())
+    }
 
-    let (below_map, above_map) = build_maps(&bricks);
+    fn part2(bricks: &Self::Input) -> Result<Answer, SolveError> {
+        let (below_map, above_map) = build_maps(bricks);
 
-    let sum = bricks
-        .iter()
-        .map(|(id, _)| {
-            let mut fallen = HashSet::from([*id]);
-            count(id, &below_map, &above_map, &mut fallen);
-            fallen.len() - 1
-        })
-        .sum();
+        let sum: usize = bricks
+            .iter()
+            .map(|(id, _)| {
+                let mut fallen = HashSet::from([*id]);
+                count(id, &below_map, &above_map, &mut fallen);
+                fallen.len() - 1
+            })
+            .sum();
 
-    sum
+        Ok(sum.into())
+    }
 }
@@ -1,20 +1,12 @@
-use std::collections::{HashMap, HashSet, LinkedList};
+use std::collections::HashMap;
 
-use itertools::Itertools;
-
-#[derive(PartialEq, Eq, Debug)]
-pub enum Cardinal {
-    N,
-    W,
-    S,
-    E,
-}
+use aoc_core::{graph::PathGraph, Answer, Direction, Grid, Pos, Solution, SolveError};
 
 #[derive(PartialEq, Eq, Debug)]
 pub enum Tile {
     Wall,
     Floor,
-    Slope(Cardinal),
+    Slope(Direction),
 }
 
 impl Tile {
@@ -22,254 +14,158 @@ impl Tile {
         match c {
             '#' => Self::Wall,
             '.' => Self::Floor,
-            '^' => Self::Slope(Cardinal::N),
-            '<' => Self::Slope(Cardinal::W),
-            'v' => Self::Slope(Cardinal::S),
-            '>' => Self::Slope(Cardinal::E),
-            _ => panic!(),
+            slope => Self::Slope(Direction::parse(slope).unwrap()),
         }
     }
 }
 
-fn parse_input(input: &str) -> (Vec<Vec<Tile>>, (usize, usize), (usize, usize)) {
-    let start = (
-        0,
-        input
-            .lines()
-            .next()
-            .unwrap()
-            .chars()
-            .find_position(|c| *c == '.')
-            .unwrap()
-            .0,
-    );
-    let end = (
-        (input.lines().count()) - 1,
-        input
-            .lines()
-            .last()
-            .unwrap()
-            .chars()
-            .find_position(|c| *c == '.')
-            .unwrap()
-            .0,
-    );
-
-    let map = input
-        .lines()
-        .map(|line| line.chars().map(|c| Tile::parse(c)).collect())
-        .collect();
-
-    (map, start, end)
+/// Where stepping `dir` from `pos` leads, if it isn't into a wall. With `slopes` set a slope can
+/// only be left downhill.
+fn step(map: &Grid<Tile>, pos: Pos, dir: Direction, slopes: bool) -> Option<Pos> {
+    match map[pos] {
+        Tile::Slope(down) if slopes && down != dir => None,
+        _ => map
+            .offset(pos, dir.delta())
+            .filter(|next| map[*next] != Tile::Wall),
+    }
 }
 
-pub fn part1(input: &str) -> usize {
-    let (map, start, end) = parse_input(input);
-
-    let mut visited = HashSet::from([start]);
-    let mut max = 0;
-
-    dfs(start, &end, &map, &mut visited, 0, &mut max);
+/// The trails as a graph between the start, the end and every tile where they fork, weighted by
+/// their length. Returns the graph with the ids of the start and the end.
+fn junctions(map: &Grid<Tile>, start: Pos, end: Pos, slopes: bool) -> (PathGraph, usize, usize) {
+    let forks = map.positions().filter(|pos| {
+        map[*pos] != Tile::Wall
+            && Direction::ALL
+                .into_iter()
+                .filter(|dir| step(map, *pos, *dir, false).is_some())
+                .count()
+                > 2
+    });
+
+    let mut ids = HashMap::new();
+    for pos in [start, end].into_iter().chain(forks) {
+        let id = ids.len();
+        ids.entry(pos).or_insert(id);
+    }
 
-    max
-}
+    let mut graph = PathGraph::new(ids.len());
+    for (from, id) in &ids {
+        'trail: for dir in Direction::ALL {
+            let Some(mut pos) = step(map, *from, dir, slopes) else {
+                continue;
+            };
 
-pub fn part2(input: &str) -> usize {
-    let (map, start, end) = parse_input(input);
+            let (mut prev, mut steps) = (*from, 1);
+            while !ids.contains_key(&pos) {
+                let next = Direction::ALL
+                    .into_iter()
+                    .filter_map(|dir| step(map, pos, dir, slopes))
+                    .find(|next| *next != prev);
+                let Some(next) = next else {
+                    continue 'trail;
+                };
 
-    let mut nodes = build_graph(start, &end, &map);
-    make_edges_directional(start, &end, &mut nodes);
+                (prev, pos, steps) = (pos, next, steps + 1);
+            }
 
-    let mut visited = HashSet::from([start]);
-    let mut max = 0;
+            if pos != *from {
+                graph.add_arc(*id, ids[&pos], steps);
+            }
+        }
+    }
 
-    dfs_nodes(start, &end, &nodes, &mut visited, 0, &mut max);
+    (graph, ids[&start], ids[&end])
+}
 
-    max
+fn no_hike() -> SolveError {
+    SolveError::Failed("no hike leads from the start to the end".to_owned())
 }
 
-fn get_neighbors(
-    (row, col): (usize, usize),
-    map: &Vec<Vec<Tile>>,
-    use_slopes: bool,
-) -> Vec<(usize, usize)> {
-    let tile = &map[row][col];
+pub struct Day23;
 
-    let directions = match *tile {
-        Tile::Slope(Cardinal::N) if use_slopes => vec![(-1, 0)],
-        Tile::Slope(Cardinal::W) if use_slopes => vec![(0, -1)],
-        Tile::Slope(Cardinal::S) if use_slopes => vec![(1, 0)],
-        Tile::Slope(Cardinal::E) if use_slopes => vec![(0, 1)],
-        _ => vec![(-1, 0), (1, 0), (0, -1), (0, 1)],
-    };
+impl Solution for Day23 {
+    type Input = (Grid<Tile>, Pos, Pos);
 
-    directions
-        .into_iter()
-        .map(|(dr, dc)| (row as isize + dr, col as isize + dc))
-        .filter(|(r, c)| *r >= 0 && *c >= 0)
-        .map(|(r, c)| (r as usize, c as usize))
-        .filter(|(r, c)| *r < map.len() && *c < map[0].len())
-        .filter(|(r, c)| map[*r][*c] != Tile::Wall)
-        .collect()
-}
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        let map = Grid::parse(input, Tile::parse);
 
-fn dfs(
-    curr: (usize, usize),
-    end: &(usize, usize),
-    map: &Vec<Vec<Tile>>,
-    visited: &mut HashSet<(usize, usize)>,
-    steps: usize,
-    max: &mut usize,
-) {
-    if curr == *end {
-        if *max < steps {
-            *max = steps;
-        }
-        return;
-    }
+        let gap = |row: usize| {
+            let col = map.row(row).iter().position(|t| *t == Tile::Floor);
+            col.map(|col| (row, col))
+                .ok_or_else(|| SolveError::Failed(format!("no path through row {row}")))
+        };
 
-    for next in get_neighbors(curr, map, true) {
-        if visited.contains(&next) {
-            continue;
-        }
+        let last = map
+            .height()
+            .checked_sub(1)
+            .ok_or_else(|| SolveError::Failed("the map is empty".to_owned()))?;
+        let (start, end) = (gap(0)?, gap(last)?);
 
-        visited.insert(next);
-        dfs(next, end, map, visited, steps + 1, max);
-        visited.remove(&next);
+        Ok((map, start, end))
     }
-}
 
-fn dfs_nodes(
-    curr: (usize, usize),
-    end: &(usize, usize),
-    nodes: &HashMap<(usize, usize), HashSet<((usize, usize), usize)>>,
-    visited: &mut HashSet<(usize, usize)>,
-    steps: usize,
-    max: &mut usize,
-) {
-    if curr == *end {
-        if *max < steps {
-            *max = steps;
-        }
-        return;
+    fn part1((map, start, end): &Self::Input) -> Result<Answer, SolveError> {
+        let (graph, start, end) = junctions(map, *start, *end, true);
+
+        graph
+            .longest(start, end)
+            .map(Answer::from)
+            .ok_or_else(no_hike)
     }
 
-    for (next, path_steps) in nodes.get(&curr).unwrap() {
-        if visited.contains(&next) {
-            continue;
-        }
+    fn part2((map, start, end): &Self::Input) -> Result<Answer, SolveError> {
+        let (graph, start, end) = junctions(map, *start, *end, false);
 
-        visited.insert(*next);
-        dfs_nodes(*next, end, nodes, visited, steps + path_steps, max);
-        visited.remove(next);
+        graph
+            .longest_parallel(start, end)
+            .map(Answer::from)
+            .ok_or_else(no_hike)
     }
 }
 
-// since it's a map and we're starting on the edge (corner even) it doesn't make sense to go back up the edges
-fn make_edges_directional(
-    start: (usize, usize),
-    end: &(usize, usize),
-    nodes: &mut HashMap<(usize, usize), HashSet<((usize, usize), usize)>>,
-) {
-    let mut queue = LinkedList::from([start]);
-
-    while !queue.is_empty() {
-        let curr = queue.pop_front().unwrap();
-
-        let next_nodes: HashSet<((usize, usize), usize)> = nodes.get(&curr).unwrap().clone();
-
-        for (next, steps) in next_nodes {
-            if next == *end {
-                break;
-            }
-
-            let next_set = nodes.get_mut(&next).unwrap();
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+
+    fn brute(
+        map: &Grid<Tile>,
+        pos: Pos,
+        end: Pos,
+        slopes: bool,
+        visited: &mut HashSet<Pos>,
+    ) -> Option<usize> {
+        if pos == end {
+            return Some(0);
+        }
 
-            if next_set.len() > 3 {
-                continue;
+        let mut longest = None;
+        for dir in Direction::ALL {
+            if let Some(next) = step(map, pos, dir, slopes) {
+                if visited.insert(next) {
+                    let rest = brute(map, next, end, slopes, visited);
+                    longest = longest.max(rest.map(|rest| rest + 1));
+                    visited.remove(&next);
+                }
             }
-
-            next_set.remove(&(curr, steps));
-            queue.push_back(next);
         }
-    }
-}
-
-fn build_graph(
-    start: (usize, usize),
-    end: &(usize, usize),
-    map: &Vec<Vec<Tile>>,
-) -> HashMap<(usize, usize), HashSet<((usize, usize), usize)>> {
-    let mut nodes = HashMap::from([(start, HashSet::new())]);
-    let mut node_queue = LinkedList::from([(start, vec![(start.0 + 1, start.1)])]);
 
-    // let mut safety = 0;
-
-    while !node_queue.is_empty() {
-        // dbg!(&node_queue);
-        let (current_node, branches) = node_queue.pop_front().unwrap();
-
-        // dbg!(current_node, &branches);
-
-        for branch in branches {
-            let mut curr_tile = branch;
-            let mut steps = 0;
-            let mut path_visited = HashSet::from([current_node, curr_tile]);
-
-            loop {
-                steps += 1;
-                // safety += 1;
-                // if safety > 100 {
-                //     panic!();
-                // }
-
-                let next: Vec<(usize, usize)> = get_neighbors(curr_tile, map, false)
-                    .into_iter()
-                    .filter(|n| !path_visited.contains(n))
-                    .collect();
-
-                // dbg!(&next);
-
-                match next.len() {
-                    // dead end
-                    0 => {
-                        if curr_tile == *end {
-                            nodes
-                                .get_mut(&current_node)
-                                .unwrap()
-                                .insert((curr_tile, steps));
-                        }
+        longest
+    }
 
-                        break;
-                    }
-                    // normal path
-                    1 => {
-                        path_visited.insert(next[0]);
-                        curr_tile = next[0];
-                    }
-                    // branching
-                    _ => {
-                        nodes
-                            .get_mut(&current_node)
-                            .unwrap()
-                            .insert((curr_tile, steps));
+    #[test]
+    fn finds_hikes_between_inner_tiles() {
+        let (map, _, _) = Day23::parse(include_str!("../fixtures/day23.txt")).unwrap();
+        let (start, end) = ((9, 1), (15, 17));
 
-                        if nodes.contains_key(&curr_tile) {
-                            nodes
-                                .get_mut(&curr_tile)
-                                .unwrap()
-                                .insert((current_node, steps));
-                        } else {
-                            node_queue.push_back((curr_tile, next));
-                            nodes.insert(curr_tile, HashSet::from([(current_node, steps)]));
-                        }
+        for slopes in [true, false] {
+            let (graph, from, to) = junctions(&map, start, end, slopes);
+            let expected = brute(&map, start, end, slopes, &mut HashSet::from([start]));
 
-                        break;
-                    }
-                }
-            }
+            assert!(expected.is_some());
+            assert_eq!(graph.longest(from, to), expected);
+            assert_eq!(graph.longest_parallel(from, to), expected);
         }
     }
-
-    nodes
 }
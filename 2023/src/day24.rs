@@ -1,202 +1,226 @@
-use std::ops;
+use std::{fmt::Display, ops::RangeInclusive};
 
+use aoc_core::{math, Answer, Point3, Solution, SolveError};
 use itertools::Itertools;
 
-#[derive(Debug, Clone, Copy, PartialEq)]
-struct Coord {
-    x: f64,
-    y: f64,
-    z: f64,
+type Coord = Point3<i128>;
+
+/// The area part 1 looks for crossings in, the examples use `7..=27`.
+pub const TEST_AREA: RangeInclusive<i64> = 200000000000000..=400000000000000;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Hailstone {
+    pos: Coord,
+    vel: Coord,
 }
 
-impl Coord {
-    fn sum(&self) -> f64 {
-        self.x + self.y + self.z
+impl Hailstone {
+    fn new(pos: &[i128], vel: &[i128]) -> Self {
+        Hailstone {
+            pos: Coord::new(pos[0], pos[1], pos[2]),
+            vel: Coord::new(vel[0], vel[1], vel[2]),
+        }
     }
 
-    fn tuple(&self) -> (f64, f64, f64) {
-        (self.x, self.y, self.z)
+    fn calc_at_t(&self, t: i128) -> Coord {
+        self.pos + (self.vel * t)
+    }
+
+    fn hits(&self, other: &Hailstone) -> bool {
+        (self.pos - other.pos).cross(&(self.vel - other.vel)) == Coord::default()
     }
 }
 
-impl ops::Add<Coord> for Coord {
-    type Output = Coord;
+/// Where two paths cross in the x/y plane, as `x / den` and `y / den` with a positive `den`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Crossing {
+    x: i128,
+    y: i128,
+    den: i128,
+}
 
-    fn add(self, rhs: Coord) -> Coord {
-        Coord {
-            x: self.x + rhs.x,
-            y: self.y + rhs.y,
-            z: self.z + rhs.z,
-        }
+impl Crossing {
+    pub fn within(&self, area: &RangeInclusive<i64>) -> bool {
+        let (low, high) = (
+            *area.start() as i128 * self.den,
+            *area.end() as i128 * self.den,
+        );
+        (low..=high).contains(&self.x) && (low..=high).contains(&self.y)
     }
 }
 
-impl ops::Sub<Coord> for Coord {
-    type Output = Coord;
+/// Where the future paths of two hailstones cross when ignoring the z axis, `None` if they are
+/// parallel or only crossed in the past.
+pub fn get_intersection_2d(h0: &Hailstone, h1: &Hailstone) -> Option<Crossing> {
+    let mut determinant = h0.vel.x * h1.vel.y - h0.vel.y * h1.vel.x;
 
-    fn sub(self, rhs: Coord) -> Coord {
-        Coord {
-            x: self.x - rhs.x,
-            y: self.y - rhs.y,
-            z: self.z - rhs.z,
-        }
+    if determinant == 0 {
+        return None;
     }
-}
 
-impl ops::Mul<f64> for Coord {
-    type Output = Coord;
+    let (dx, dy) = (h1.pos.x - h0.pos.x, h1.pos.y - h0.pos.y);
+    let mut time = dx * h1.vel.y - dy * h1.vel.x;
+    let mut time2 = dx * h0.vel.y - dy * h0.vel.x;
 
-    fn mul(self, rhs: f64) -> Coord {
-        Coord {
-            x: self.x * rhs,
-            y: self.y * rhs,
-            z: self.z * rhs,
-        }
+    if determinant < 0 {
+        (determinant, time, time2) = (-determinant, -time, -time2);
     }
+    if time < 0 || time2 < 0 {
+        return None;
+    }
+
+    Some(Crossing {
+        x: h0.pos.x * determinant + h0.vel.x * time,
+        y: h0.pos.y * determinant + h0.vel.y * time,
+        den: determinant,
+    })
 }
 
-impl ops::Div<f64> for Coord {
-    type Output = Coord;
+pub fn count_crossings(hailstones: &[Hailstone], area: &RangeInclusive<i64>) -> usize {
+    hailstones
+        .iter()
+        .tuple_combinations()
+        .filter_map(|(h0, h1)| get_intersection_2d(h0, h1))
+        .filter(|crossing| crossing.within(area))
+        .count()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ThrowError {
+    /// Every pair of hailstones leaves the rock's path undetermined.
+    Degenerate,
+    /// The only path that could work misses a hailstone or needs fractional times.
+    NoThrow,
+}
 
-    fn div(self, rhs: f64) -> Coord {
-        Coord {
-            x: self.x / rhs,
-            y: self.y / rhs,
-            z: self.z / rhs,
+impl Display for ThrowError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Degenerate => write!(f, "the hailstones don't pin down a single throw"),
+            Self::NoThrow => write!(f, "no throw at whole times hits every hailstone"),
         }
     }
 }
 
-#[derive(Debug, Clone)]
-struct Hailstone {
-    pos: Coord,
-    vel: Coord,
+impl std::error::Error for ThrowError {}
+
+/// The time `t` at which `pos + t * vel` lies on the line through the origin along `dir`.
+fn meet_time(pos: Coord, vel: Coord, dir: Coord) -> Option<i128> {
+    let (offset, speed) = (pos.cross(&dir), vel.cross(&dir));
+    let (a, b) = [
+        (offset.x, speed.x),
+        (offset.y, speed.y),
+        (offset.z, speed.z),
+    ]
+    .into_iter()
+    .find(|(_, b)| *b != 0)?;
+
+    (a % b == 0)
+        .then_some(-a / b)
+        .filter(|t| offset + speed * *t == Coord::default())
 }
-impl Hailstone {
-    fn new(pos: Vec<f64>, vel: Vec<f64>) -> Self {
-        Hailstone {
-            pos: Coord {
-                x: pos[0],
-                y: pos[1],
-                z: pos[2],
-            },
-            vel: Coord {
-                x: vel[0],
-                y: vel[1],
-                z: vel[2],
-            },
+
+/// Finds the rock's starting position and velocity. Seen from the first hailstone the rock's path
+/// goes through the origin, so it lies in the plane through the origin and any other hailstone's
+/// path, and two such planes meet along the rock's direction.
+pub fn throw(hailstones: &[Hailstone]) -> Result<Hailstone, ThrowError> {
+    let Some((h0, rest)) = hailstones.split_first() else {
+        return Err(ThrowError::Degenerate);
+    };
+    let relative = |h: &Hailstone| (h.pos - h0.pos, h.vel - h0.vel);
+
+    for (h1, h2) in rest.iter().tuple_combinations() {
+        let ((p1, v1), (p2, v2)) = (relative(h1), relative(h2));
+        let dir = p1.cross(&v1).cross(&p2.cross(&v2));
+        if dir == Coord::default() {
+            continue;
         }
-    }
 
-    fn make_relative_to(&mut self, other: &Hailstone) {
-        self.pos = self.pos - other.pos;
-        self.vel = self.vel - other.vel;
-    }
+        let scale = math::gcd(math::gcd(dir.x.abs(), dir.y.abs()), dir.z.abs());
+        let dir = dir / scale;
 
-    fn calc_at_t(&self, t: f64) -> Coord {
-        self.pos + (self.vel * t)
-    }
-}
+        let (Some(t1), Some(t2)) = (meet_time(p1, v1, dir), meet_time(p2, v2, dir)) else {
+            continue;
+        };
+        if t1 == t2 {
+            continue;
+        }
 
-fn get_intersection_2d(h0: &Hailstone, h1: &Hailstone) -> Option<(f64, f64)> {
-    let determinant = h0.vel.x * h1.vel.y - h0.vel.y * h1.vel.x;
+        let (c1, c2) = (h1.calc_at_t(t1), h2.calc_at_t(t2));
+        let (delta, dt) = (c2 - c1, t2 - t1);
+        if [delta.x, delta.y, delta.z].iter().any(|d| d % dt != 0) {
+            return Err(ThrowError::NoThrow);
+        }
 
-    if determinant == 0.0 {
-        return None;
+        let vel = delta / dt;
+        let rock = Hailstone {
+            pos: c1 - vel * t1,
+            vel,
+        };
+
+        return match hailstones.iter().all(|h| rock.hits(h)) {
+            true => Ok(rock),
+            false => Err(ThrowError::NoThrow),
+        };
     }
 
-    let time = ((h1.pos.x - h0.pos.x) * h1.vel.y - (h1.pos.y - h0.pos.y) * h1.vel.x) / determinant;
-    let time2 = ((h0.pos.x - h1.pos.x) * h0.vel.y - (h0.pos.y - h1.pos.y) * h0.vel.x) / determinant;
+    Err(ThrowError::Degenerate)
+}
 
-    if time < 0.0 {
-        return None;
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Input = Vec<Hailstone>;
+
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        input
+            .lines()
+            .map(|line| {
+                let vectors = line
+                    .split(" @ ")
+                    .map(|vector| vector.split(',').map(|p| p.trim().parse()).collect())
+                    .collect::<Result<Vec<Vec<i128>>, _>>()?;
+
+                match vectors.as_slice() {
+                    [pos, vel] if pos.len() == 3 && vel.len() == 3 => Ok(Hailstone::new(pos, vel)),
+                    _ => Err(SolveError::Failed(format!("not a hailstone: {line}"))),
+                }
+            })
+            .collect()
     }
-    if time * time2 > 0.0 {
-        return None;
+
+    fn part1(hailstones: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(count_crossings(hailstones, &TEST_AREA).into())
     }
-    let x = h0.pos.x + h0.vel.x * time;
-    let y = h0.pos.y + h0.vel.y * time;
 
-    Some((x, y))
-}
+    fn part2(hailstones: &Self::Input) -> Result<Answer, SolveError> {
+        let rock = throw(hailstones)?;
 
-fn parse_input(input: &str) -> Vec<Hailstone> {
-    input
-        .lines()
-        .map(|line| line.split_once(" @ ").unwrap())
-        .map(|(pos, vel)| {
-            [pos, vel]
-                .into_iter()
-                .map(|vector| {
-                    vector
-                        .split(',')
-                        .map(|p| p.trim().parse().unwrap())
-                        .collect()
-                })
-                .collect_tuple()
-                .unwrap()
-        })
-        .map(|(pos, vel)| Hailstone::new(pos, vel))
-        .collect()
+        Ok((rock.pos.sum() as i64).into())
+    }
 }
 
-pub fn part1(input: &str) -> isize {
-    let area: (f64, f64) = (200000000000000.0, 400000000000000.0);
-    let hailstones = parse_input(input);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    let (lower, upper) = area;
-    let mut collisions = 0;
-    for combinations in hailstones.iter().combinations(2) {
-        if let Some(intersection) = get_intersection_2d(combinations[0], combinations[1]) {
-            let (x, y) = intersection;
+    const EXAMPLE: &str = include_str!("../fixtures/day24.txt");
 
-            if (x > lower && x < upper) && (y > lower && y < upper) {
-                collisions += 1;
-            }
-        }
-    }
+    #[test]
+    fn counts_crossings_in_example_area() {
+        let hailstones = Day24::parse(EXAMPLE).unwrap();
 
-    return collisions;
-}
+        assert_eq!(count_crossings(&hailstones, &(7..=27)), 2);
+    }
 
-pub fn part2(input: &str) -> u64 {
-    let hailstones = parse_input(input);
+    #[test]
+    fn reports_degenerate_throws() {
+        let hailstones = Day24::parse(EXAMPLE).unwrap();
 
-    // find 3 hailstones with one same velocity component (it really makes the algebra easier)
-    // I found one with x y and z, I suspect this is by design, let's go with x
-    let (h0, h1, h2) = hailstones
-        .iter()
-        .combinations(3)
-        .map(|combo| (combo[0], combo[1], combo[2]))
-        .filter(|(h0, h1, h2)| (h0.vel.x == h1.vel.x && h0.vel.x == h2.vel.x))
-        .next()
-        .unwrap();
-
-    // make h0 the origin
-    let mut h1_rel = h1.clone();
-    let mut h2_rel = h2.clone();
-    h1_rel.make_relative_to(h0);
-    h2_rel.make_relative_to(h0);
-
-    // collisions are on the same line going through origin and (p1+t1*v1) and (p2+t2*v2), call them cv1 and cv2
-    // since they are on the same line, cv1 = m*cv2, where m is some scalar
-    // we can normalize both by dividing by one coord to get rid of m. if we divide by the coord where ther relative velocity is 0, this is pretty easy
-    // after some algebra on paper...
-    let (x1, y1, z1) = h1_rel.pos.tuple();
-    let (x2, y2, z2) = h2_rel.pos.tuple();
-    let (_vx1, vy1, vz1) = h1_rel.vel.tuple();
-    let (_vx2, vy2, vz2) = h2_rel.vel.tuple();
-
-    let t2 =
-        (y2 * vz1 - y1 * vz1 * x2 / x1 + z1 * vy1 * x2 / x1 - z2 * vy1) / (vy1 * vz2 - vy2 * vz1);
-
-    let t1 = (t2 * vy2 * x1 + y2 * x1 - y1 * x2) / (vy1 * x2);
-
-    let collision_1 = h1.calc_at_t(t1);
-    let collision_2 = h2.calc_at_t(t2);
-
-    let rock_velocity = (collision_2 - collision_1) / (t2 - t1);
-    let initial_position = collision_1 - (rock_velocity * t1);
-
-    initial_position.sum().floor() as u64
+        assert_eq!(throw(&hailstones[..2]), Err(ThrowError::Degenerate));
+        assert_eq!(
+            throw(&hailstones).map(|rock| (rock.pos, rock.vel)),
+            Ok((Coord::new(24, 13, 10), Coord::new(-3, 1, 2)))
+        );
+    }
 }
@@ -1,106 +1,95 @@
-use std::{
-    collections::{HashMap, HashSet},
-    fs::File,
-    io::Write,
-    process::Command,
-};
+use std::{collections::HashMap, io};
 
-use itertools::Itertools;
+use aoc_core::{graph, Answer, Solution, SolveError};
 
-pub fn create_graph(input: &str) {
-    let mut file = File::create("./day25.dot").expect("failed to create dot file");
-    file.write("graph {\n".as_bytes()).unwrap();
-
-    input.lines().for_each(|line| {
-        let from = line.split(":").next().unwrap();
-        let to: String = line
-            .split(":")
-            .skip(1)
-            .next()
-            .unwrap()
-            .trim()
-            .split_whitespace()
-            .join(", ");
-
-        file.write(format!("  {} -- {{{}}}\n", from, to).as_bytes())
-            .unwrap();
-    });
-    file.write("}".as_bytes()).unwrap();
-
-    Command::new("dot")
-        .arg("-Tsvg")
-        .arg("./day25.dot")
-        .arg("-o")
-        .arg("./day25.svg")
-        .arg("-Kneato")
-        .spawn()
-        .expect("failed to create vg from dot file");
+pub struct Wiring {
+    components: Vec<String>,
+    wires: Vec<(usize, usize)>,
 }
 
-pub fn part1(input: &str) -> usize {
-    create_graph(input);
-
-    let mut map: HashMap<&str, HashSet<&str>> = HashMap::new();
-
-    input.lines().for_each(|line| {
-        let from = line.split(":").next().unwrap();
-        let to: HashSet<&str> = line
-            .split(":")
-            .skip(1)
-            .next()
-            .unwrap()
-            .trim()
-            .split_whitespace()
-            .collect();
-
-        if let Some(curr) = map.get_mut(from) {
-            curr.extend(&to);
-        } else {
-            map.insert(from, to.clone());
+impl Wiring {
+    fn parse(input: &str) -> Result<Self, SolveError> {
+        let mut ids: HashMap<&str, usize> = HashMap::new();
+        let mut components = Vec::new();
+        let mut id = |name| {
+            *ids.entry(name).or_insert_with(|| {
+                components.push(name.to_owned());
+                components.len() - 1
+            })
+        };
+
+        let mut wires = Vec::new();
+        for line in input.lines() {
+            let (from, to) = line.split_once(':').ok_or_else(|| {
+                SolveError::Failed(format!("no ':' after the component in {line}"))
+            })?;
+            let from = id(from);
+            wires.extend(to.split_whitespace().map(|to| (from, id(to))));
         }
 
-        for node in to {
-            if let Some(curr) = map.get_mut(node) {
-                curr.insert(from);
-            } else {
-                map.insert(node, HashSet::from([from]));
-            }
-        }
-    });
+        Ok(Wiring { components, wires })
+    }
+}
 
-    // from graphviz
-    // jkn-cfn
-    // gst-rph
-    // ljm-sfd
+/// Writes the wiring as a Graphviz graph, `neato` lays it out so the three wires to cut stand
+/// out.
+pub fn write_dot(input: &str, out: &mut impl io::Write) -> io::Result<()> {
+    let wiring = Wiring::parse(input)
+        .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err.to_string()))?;
+
+    writeln!(out, "graph {{")?;
+    for (a, b) in &wiring.wires {
+        writeln!(
+            out,
+            "  {} -- {}",
+            wiring.components[*a], wiring.components[*b]
+        )?;
+    }
+    writeln!(out, "}}")
+}
 
-    map.get_mut("jkn").unwrap().remove("cfn");
-    map.get_mut("cfn").unwrap().remove("jkn");
-    map.get_mut("gst").unwrap().remove("rph");
-    map.get_mut("rph").unwrap().remove("gst");
-    map.get_mut("ljm").unwrap().remove("sfd");
-    map.get_mut("sfd").unwrap().remove("ljm");
+pub struct Day25;
 
-    let mut visited = HashSet::from(["jkn"]);
-    cnt("jkn", &map, &mut visited);
-    let side_a = visited.len();
+impl Solution for Day25 {
+    type Input = Wiring;
 
-    let mut visited = HashSet::from(["cfn"]);
-    cnt("cfn", &map, &mut visited);
-    let side_b = visited.len();
+    const PARTS: u8 = 1;
 
-    side_a * side_b
-}
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        Wiring::parse(input)
+    }
 
-fn cnt<'a>(curr: &str, map: &HashMap<&str, HashSet<&'a str>>, visited: &mut HashSet<&'a str>) {
-    for next in map.get(curr).unwrap() {
-        if !visited.contains(next) {
-            visited.insert(next);
-            cnt(next, map, visited);
+    fn part1(wiring: &Self::Input) -> Result<Answer, SolveError> {
+        let nodes = wiring.components.len();
+        match graph::min_cut(nodes, &wiring.wires) {
+            Some(cut) if cut.size == 3 => Ok((cut.side.len() * (nodes - cut.side.len())).into()),
+            Some(cut) => Err(SolveError::Failed(format!(
+                "the smallest cut is {} wires, not 3",
+                cut.size
+            ))),
+            None => Err(SolveError::Failed(
+                "there are too few components to cut".to_owned(),
+            )),
         }
     }
 }
 
-pub fn part2(_input: &str) -> u8 {
-    //stub
-    0
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reports_wiring_without_a_three_wire_cut() {
+        let wiring = Day25::parse("a: b").unwrap();
+        assert_eq!(
+            Day25::part1(&wiring),
+            Err(SolveError::Failed(
+                "the smallest cut is 1 wires, not 3".to_owned()
+            ))
+        );
+
+        let single = Day25::parse("a:").unwrap();
+        assert!(Day25::part1(&single).is_err());
+        assert!(Day25::parse("a b").is_err());
+    }
 }
@@ -1,3 +1,5 @@
+use aoc_core::{Grid, Pos};
+
 #[derive(Debug)]
 pub struct PartNumber {
     value: u32,
@@ -16,7 +18,7 @@ impl PartNumber {
         }
     }
 
-    fn get_neighbors(&self, row_size: usize, col_size: usize) -> Vec<(usize, usize)> {
+    fn get_neighbors(&self, map: &Grid<char>) -> Vec<Pos> {
         let mut neighbors: Vec<(isize, isize)> = Vec::new();
         neighbors.extend(
             [
@@ -42,23 +44,24 @@ impl PartNumber {
 
         neighbors
             .into_iter()
-            .filter(|(row, col)| *row > 0 && *col > 0)
-            .map(|(row, col)| (row as usize, col as usize))
-            .filter(|(row, col)| *row < row_size && *col < col_size)
+            .filter_map(|(row, col)| {
+                let pos = (usize::try_from(row).ok()?, usize::try_from(col).ok()?);
+                map.in_bounds(pos).then_some(pos)
+            })
             .collect()
     }
 
-    pub fn is_valid(&self, map: &Vec<Vec<char>>) -> bool {
-        self.get_neighbors(map.len(), map.get(0).unwrap().len())
+    pub fn is_valid(&self, map: &Grid<char>) -> bool {
+        self.get_neighbors(map)
             .into_iter()
-            .map(|(row, col)| map.get(row).unwrap().get(col).unwrap())
-            .any(|c| !c.is_numeric() && *c != '.')
+            .map(|pos| map[pos])
+            .any(|c| !c.is_numeric() && c != '.')
     }
 
-    pub fn get_gears(&self, map: &Vec<Vec<char>>) -> Vec<(usize, usize)> {
-        self.get_neighbors(map.len(), map.get(0).unwrap().len())
+    pub fn get_gears(&self, map: &Grid<char>) -> Vec<Pos> {
+        self.get_neighbors(map)
             .into_iter()
-            .filter(|(row, col)| *map.get(*row).unwrap().get(*col).unwrap() == '*')
+            .filter(|pos| map[*pos] == '*')
             .collect()
     }
 
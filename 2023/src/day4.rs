@@ -1,75 +1,82 @@
 use std::collections::HashSet;
 
+use aoc_core::{Answer, Solution, SolveError};
 use regex::Regex;
 
-fn parse_input(input: &str) -> Vec<(HashSet<u8>, Vec<u8>)> {
-    let re = Regex::new(r"^Card\s+\d+:\s+([\d\s]+)\s\|\s+([\d\s]+)$").unwrap();
+pub struct Day4;
 
-    input
-        .lines()
-        .map(|line| {
-            let cap = re.captures(line).unwrap();
-            let winning = cap.get(1).unwrap().as_str();
-            let actual = cap.get(2).unwrap().as_str();
+impl Solution for Day4 {
+    type Input = Vec<(HashSet<u8>, Vec<u8>)>;
 
-            let winning = winning
-                .split_whitespace()
-                .map(|num| num.parse::<u8>().unwrap())
-                .collect::<HashSet<u8>>();
-            let actual = actual
-                .split_whitespace()
-                .map(|num| num.parse::<u8>().unwrap())
-                .collect::<Vec<u8>>();
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        let re = Regex::new(r"^Card\s+\d+:\s+([\d\s]+)\s\|\s+([\d\s]+)$").unwrap();
 
-            (winning, actual)
-        })
-        .collect()
-}
+        Ok(input
+            .lines()
+            .map(|line| {
+                let cap = re.captures(line).unwrap();
+                let winning = cap.get(1).unwrap().as_str();
+                let actual = cap.get(2).unwrap().as_str();
 
-pub fn part1(input: &str) -> u32 {
-    let cards = parse_input(input);
+                let winning = winning
+                    .split_whitespace()
+                    .map(|num| num.parse::<u8>().unwrap())
+                    .collect::<HashSet<u8>>();
+                let actual = actual
+                    .split_whitespace()
+                    .map(|num| num.parse::<u8>().unwrap())
+                    .collect::<Vec<u8>>();
 
-    cards.iter().fold(0, |sum, (winning, actual)| {
-        let mut count = 0;
-        actual.iter().for_each(|num| {
-            if winning.contains(num) {
-                count += 1;
-            }
-        });
+                (winning, actual)
+            })
+            .collect())
+    }
 
-        if count == 0 {
-            return sum;
-        }
+    fn part1(cards: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(cards
+            .iter()
+            .fold(0, |sum, (winning, actual)| {
+                let mut count = 0;
+                actual.iter().for_each(|num| {
+                    if winning.contains(num) {
+                        count += 1;
+                    }
+                });
 
-        sum + u32::pow(2, count - 1)
-    })
-}
+                if count == 0 {
+                    return sum;
+                }
 
-pub fn part2(input: &str) -> u32 {
-    let cards = parse_input(input);
-    let mut counts = vec![1; cards.len()];
+                sum + u32::pow(2, count - 1)
+            })
+            .into())
+    }
 
-    cards
-        .iter()
-        .enumerate()
-        .for_each(|(idx, (winning, actual))| {
-            let mut count = 0;
-            actual.iter().for_each(|num| {
-                if winning.contains(num) {
-                    count += 1;
-                }
-            });
+    fn part2(cards: &Self::Input) -> Result<Answer, SolveError> {
+        let mut counts = vec![1; cards.len()];
+
+        cards
+            .iter()
+            .enumerate()
+            .for_each(|(idx, (winning, actual))| {
+                let mut count = 0;
+                actual.iter().for_each(|num| {
+                    if winning.contains(num) {
+                        count += 1;
+                    }
+                });
 
-            let idx_count = counts.get(idx).unwrap().to_owned();
+                let idx_count = counts.get(idx).unwrap().to_owned();
 
-            for i in idx + 1..idx + 1 + count {
-                if let Some(elem) = counts.get_mut(i) {
-                    *elem += idx_count;
-                } else {
-                    break;
+                for i in idx + 1..idx + 1 + count {
+                    if let Some(elem) = counts.get_mut(i) {
+                        *elem += idx_count;
+                    } else {
+                        break;
+                    }
                 }
-            }
-        });
+            });
 
-    counts.into_iter().reduce(|sum, c| sum + c).unwrap()
+        Ok(counts.into_iter().sum::<u32>().into())
+    }
 }
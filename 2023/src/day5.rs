@@ -1,3 +1,4 @@
+use aoc_core::{Answer, Interval, IntervalSet, Solution, SolveError};
 use itertools::Itertools;
 
 use self::map::Map;
@@ -14,68 +15,68 @@ mod map;
 6: humidity-to-location
 */
 
-fn parse_input(input: &str) -> (Vec<i64>, Vec<Map>) {
-    let mut parts = input.split("\n\n");
-    let seeds = parts.next().unwrap();
-    let seeds = seeds
-        .strip_prefix("seeds: ")
-        .unwrap()
-        .split(" ")
-        .map(|seed| seed.parse::<i64>().unwrap())
-        .collect::<Vec<i64>>();
+pub struct Day5;
 
-    let maps = parts
-        .map(|map| -> Vec<(i64, i64, i64)> {
-            map.lines()
-                .skip(1)
-                .map(|line| {
-                    line.split(" ")
-                        .map(|val| val.parse::<i64>().unwrap())
-                        .collect_tuple()
-                        .unwrap()
-                })
-                .collect()
-        })
-        .map(|ranges| Map::new(ranges))
-        .collect();
+impl Solution for Day5 {
+    type Input = (Vec<i64>, Vec<Map>);
 
-    (seeds, maps)
-}
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        let mut parts = input.split("\n\n");
+        let seeds = parts.next().unwrap();
+        let seeds = seeds
+            .strip_prefix("seeds: ")
+            .unwrap()
+            .split(' ')
+            .map(|seed| seed.parse::<i64>().unwrap())
+            .collect::<Vec<i64>>();
 
-pub fn part1(input: &str) -> i64 {
-    let (seeds, maps) = parse_input(input);
+        let maps = parts
+            .map(|map| -> Vec<(i64, i64, i64)> {
+                map.lines()
+                    .skip(1)
+                    .map(|line| {
+                        line.split(' ')
+                            .map(|val| val.parse::<i64>().unwrap())
+                            .collect_tuple()
+                            .unwrap()
+                    })
+                    .collect()
+            })
+            .map(Map::new)
+            .collect();
 
-    seeds.iter().fold(i64::MAX, |min_location, seed| {
-        let location = maps
-            .iter()
-            .fold(*seed, |current, map| map.translate(current));
+        Ok((seeds, maps))
+    }
 
-        location.min(min_location)
-    })
-}
+    fn part1((seeds, maps): &Self::Input) -> Result<Answer, SolveError> {
+        Ok(seeds
+            .iter()
+            .fold(i64::MAX, |min_location, seed| {
+                let location = maps
+                    .iter()
+                    .fold(*seed, |current, map| map.translate(current));
 
-// create extra ranges for everything
-// only check min of each range
+                location.min(min_location)
+            })
+            .into())
+    }
 
-pub fn part2(input: &str) -> i64 {
-    let (seeds, maps) = parse_input(input);
+    // create extra ranges for everything
+    // only check min of each range
 
-    let mut current = seeds
-        .iter()
-        .batching(|it| match it.next() {
-            None => None,
-            Some(start) => match it.next() {
+    fn part2((seeds, maps): &Self::Input) -> Result<Answer, SolveError> {
+        let mut current = seeds
+            .iter()
+            .batching(|it| match it.next() {
                 None => None,
-                Some(len) => Some((*start, start + len)),
-            },
-        })
-        .collect::<Vec<(i64, i64)>>();
+                Some(start) => it.next().map(|len| Interval::new(*start, start + len)),
+            })
+            .collect::<IntervalSet<i64>>();
 
-    maps.iter().for_each(|map| {
-        current = map.get_possible_ranges(&current);
-    });
+        maps.iter().for_each(|map| {
+            current = map.get_possible_ranges(&current);
+        });
 
-    current
-        .into_iter()
-        .fold(i64::MAX, |min, (start, _)| min.min(start))
+        Ok(current.min().unwrap().into())
+    }
 }
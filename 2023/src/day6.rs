@@ -1,26 +1,6 @@
+use aoc_core::{Answer, Solution, SolveError};
 use itertools::Itertools;
 
-fn parse_input(input: &str) -> Vec<Vec<f64>> {
-    input
-        .lines()
-        .map(|line| {
-            let mut strip = true;
-            line.trim_start_matches(|c| {
-                if c == ':' {
-                    strip = false;
-                    return true;
-                }
-
-                return strip;
-            })
-            .trim()
-            .split_whitespace()
-            .map(|num| num.parse::<f64>().unwrap())
-            .collect()
-        })
-        .collect()
-}
-
 fn calc_winning((t, d): (f64, f64)) -> u32 {
     // -x^2 + Tx - D
     let root_term = (t.powi(2) - 4.0 * d).sqrt();
@@ -30,32 +10,55 @@ fn calc_winning((t, d): (f64, f64)) -> u32 {
     let min = (r1 + 1.0).floor() as u32;
     let max = (r2 - 1.0).ceil() as u32;
 
-    return max - min + 1;
+    max - min + 1
 }
 
-pub fn part1(input: &str) -> u32 {
-    let numbers = parse_input(input);
-
-    numbers
-        .get(0)
-        .unwrap()
-        .into_iter()
-        .zip(numbers.get(1).unwrap().into_iter())
-        .map(|(time, distance)| (*time, *distance))
-        .map(calc_winning)
-        .reduce(|prod, curr| prod * curr)
-        .unwrap() as u32
-}
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input = Vec<Vec<f64>>;
+
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        Ok(input
+            .lines()
+            .map(|line| {
+                let mut strip = true;
+                line.trim_start_matches(|c| {
+                    if c == ':' {
+                        strip = false;
+                        return true;
+                    }
+
+                    strip
+                })
+                .split_whitespace()
+                .map(|num| num.parse::<f64>().unwrap())
+                .collect()
+            })
+            .collect())
+    }
+
+    fn part1(numbers: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(numbers
+            .first()
+            .unwrap()
+            .iter()
+            .zip(numbers.get(1).unwrap().iter())
+            .map(|(time, distance)| (*time, *distance))
+            .map(calc_winning)
+            .product::<u32>()
+            .into())
+    }
 
-pub fn part2(input: &str) -> u32 {
-    let numbers = parse_input(input);
-
-    calc_winning(
-        numbers
-            .into_iter()
-            .map(|line| line.into_iter().map(|num| num.to_string()).join(""))
-            .map(|num| num.parse::<f64>().unwrap())
-            .collect_tuple()
-            .unwrap(),
-    )
+    fn part2(numbers: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(calc_winning(
+            numbers
+                .iter()
+                .map(|line| line.iter().map(|num| num.to_string()).join(""))
+                .map(|num| num.parse::<f64>().unwrap())
+                .collect_tuple()
+                .unwrap(),
+        )
+        .into())
+    }
 }
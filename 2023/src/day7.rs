@@ -1,37 +1,42 @@
+use aoc_core::{Answer, Solution, SolveError};
 use itertools::Itertools;
 
 use self::hand::Hand;
 
 mod hand;
 
-fn parse_input(input: &str) -> Vec<(String, u32)> {
-    input
-        .lines()
-        .map(|line| {
-            let (hand, bid): (&str, &str) = line.split(" ").collect_tuple().unwrap();
-            (hand.to_owned(), bid.parse::<u32>().unwrap())
-        })
-        .collect()
-}
+pub struct Day7;
 
-pub fn part1(input: &str) -> u32 {
-    let hands = parse_input(input);
+impl Solution for Day7 {
+    type Input = Vec<(String, u32)>;
 
-    hands
-        .iter()
-        .map(|(cards, bid)| (Hand::new(&cards, false), bid))
-        .sorted_by(|(h_a, _), (h_b, _)| h_a.cmp(h_b))
-        .enumerate()
-        .fold(0, |sum, (idx, (_, bid))| sum + ((idx as u32 + 1) * bid))
-}
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        Ok(input
+            .lines()
+            .map(|line| {
+                let (hand, bid): (&str, &str) = line.split(' ').collect_tuple().unwrap();
+                (hand.to_owned(), bid.parse::<u32>().unwrap())
+            })
+            .collect())
+    }
 
-pub fn part2(input: &str) -> u32 {
-    let hands = parse_input(input);
+    fn part1(hands: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(hands
+            .iter()
+            .map(|(cards, bid)| (Hand::new(cards, false), bid))
+            .sorted_by(|(h_a, _), (h_b, _)| h_a.cmp(h_b))
+            .enumerate()
+            .fold(0, |sum, (idx, (_, bid))| sum + ((idx as u32 + 1) * bid))
+            .into())
+    }
 
-    hands
-        .iter()
-        .map(|(cards, bid)| (Hand::new(&cards, true), bid))
-        .sorted_by(|(h_a, _), (h_b, _)| h_a.cmp(h_b))
-        .enumerate()
-        .fold(0, |sum, (idx, (_, bid))| sum + ((idx as u32 + 1) * bid))
+    fn part2(hands: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(hands
+            .iter()
+            .map(|(cards, bid)| (Hand::new(cards, true), bid))
+            .sorted_by(|(h_a, _), (h_b, _)| h_a.cmp(h_b))
+            .enumerate()
+            .fold(0, |sum, (idx, (_, bid))| sum + ((idx as u32 + 1) * bid))
+            .into())
+    }
 }
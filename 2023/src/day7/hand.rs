@@ -45,7 +45,7 @@ impl HandType {
             .into_iter()
             .filter(|(_, count)| *count == 2)
             .count();
-        return HandType(pairs as u8); // 2 pairs, 1 pair, highcard
+        HandType(pairs as u8) // 2 pairs, 1 pair, highcard
     }
 
     fn calc_type_joker(cards: &[Card; 5]) -> Self {
@@ -88,7 +88,7 @@ impl HandType {
             return HandType(3);
         }
 
-        return HandType(1);
+        HandType(1)
     }
 
     pub fn new(cards: &[Card; 5], use_joker: bool) -> Self {
@@ -134,18 +134,15 @@ impl Hand {
 
 impl PartialOrd for Hand {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        match self.hand_type.partial_cmp(&other.hand_type) {
-            Some(Ordering::Equal) => self.cards.partial_cmp(&other.cards),
-            ord => ord,
-        }
+        Some(self.cmp(other))
     }
 }
 
 impl Ord for Hand {
     fn cmp(&self, other: &Self) -> Ordering {
-        match self.partial_cmp(&other) {
-            None => Ordering::Equal,
-            Some(ord) => ord,
+        match self.hand_type.cmp(&other.hand_type) {
+            Ordering::Equal => self.cards.cmp(&other.cards),
+            ord => ord,
         }
     }
 }
@@ -1,36 +1,13 @@
 use std::collections::HashMap;
 
+use aoc_core::{
+    cycle::{self, Schedule},
+    Answer, Solution, SolveError,
+};
 use itertools::Itertools;
 use regex::Regex;
 
-fn parse_input(input: &str) -> (Vec<char>, HashMap<String, (String, String)>) {
-    let re = Regex::new(r"^([A-Z]{3}) = \(([A-Z]{3}), ([A-Z]{3})\)$").unwrap();
-
-    let (steps, nodes): (&str, &str) = input.split("\n\n").collect_tuple().unwrap();
-
-    let map: HashMap<String, (String, String)> = nodes
-        .lines()
-        .map(|line| {
-            let cap = re.captures(line).unwrap();
-            (
-                cap.get(1).unwrap().as_str().to_owned(),
-                (
-                    cap.get(2).unwrap().as_str().to_owned(),
-                    cap.get(3).unwrap().as_str().to_owned(),
-                ),
-            )
-        })
-        .collect();
-
-    (steps.chars().collect(), map)
-}
-
-fn count_steps(
-    start: &str,
-    zzz: bool,
-    steps: &Vec<char>,
-    map: &HashMap<String, (String, String)>,
-) -> u64 {
+fn count_steps(start: &str, steps: &[char], map: &HashMap<String, (String, String)>) -> u64 {
     let mut current = map.get(start).unwrap();
 
     let mut count = 0;
@@ -41,14 +18,8 @@ fn count_steps(
             _ => &current.1,
         };
 
-        if zzz {
-            if next == "ZZZ" {
-                break;
-            }
-        } else {
-            if next.ends_with('Z') {
-                break;
-            }
+        if next == "ZZZ" {
+            break;
         }
 
         current = map.get(next).unwrap();
@@ -57,47 +28,90 @@ fn count_steps(
     count
 }
 
-fn gcd(a_start: u64, b_start: u64) -> u64 {
-    let mut a = a_start;
-    let mut b = b_start;
+/// When a ghost following the steps from `start` is on a Z node.
+fn on_z(start: &str, steps: &[char], map: &HashMap<String, (String, String)>) -> Schedule {
+    let mut on_z = Vec::new();
+    let mut state = (start, 0);
+    let cycle = cycle::find_cycle(
+        &mut state,
+        |(node, step)| {
+            let (left, right) = &map[*node];
+            *node = if steps[*step] == 'L' { left } else { right };
+            *step = (*step + 1) % steps.len();
+        },
+        |(node, step)| {
+            on_z.push(node.ends_with('Z'));
+            (*node, *step)
+        },
+    );
+
+    // the last state seen is the first repeat, which is already covered by `cycle.start`
+    on_z.truncate(cycle.start + cycle.length);
+    let times = on_z.into_iter().enumerate().filter(|(_, z)| *z);
+    Schedule::new(cycle, times.map(|(t, _)| t))
+}
 
-    // recursive algo runs out of stack on default rust config
-    loop {
-        if a == b {
-            return a;
-        }
+pub struct Day8;
 
-        if a > b {
-            a = a - b;
-            continue;
-        }
+impl Solution for Day8 {
+    type Input = (Vec<char>, HashMap<String, (String, String)>);
+
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        let re = Regex::new(r"^([0-9A-Z]{3}) = \(([0-9A-Z]{3}), ([0-9A-Z]{3})\)$").unwrap();
 
-        b = b - a;
+        let (steps, nodes): (&str, &str) = input.split("\n\n").collect_tuple().unwrap();
+
+        let map: HashMap<String, (String, String)> = nodes
+            .lines()
+            .map(|line| {
+                let cap = re.captures(line).unwrap();
+                (
+                    cap.get(1).unwrap().as_str().to_owned(),
+                    (
+                        cap.get(2).unwrap().as_str().to_owned(),
+                        cap.get(3).unwrap().as_str().to_owned(),
+                    ),
+                )
+            })
+            .collect();
+
+        Ok((steps.chars().collect(), map))
     }
-}
 
-fn lcm(a: u64, b: u64) -> u64 {
-    a * b / gcd(a, b)
-}
+    fn part1((steps, map): &Self::Input) -> Result<Answer, SolveError> {
+        Ok(count_steps("AAA", steps, map).into())
+    }
 
-pub fn part1(input: &str) -> u64 {
-    let (steps, map) = parse_input(input);
-    count_steps("AAA", true, &steps, &map)
+    fn part2((steps, map): &Self::Input) -> Result<Answer, SolveError> {
+        let starts = map
+            .iter()
+            .filter(|(point, _)| point.ends_with('A'))
+            .map(|(point, _)| point.to_owned())
+            .collect::<Vec<String>>();
+
+        let ghosts: Vec<Schedule> = starts.iter().map(|start| on_z(start, steps, map)).collect();
+
+        cycle::first_common(&ghosts)
+            .map(Answer::from)
+            .ok_or_else(|| SolveError::Failed("ghosts never all reach Z together".to_owned()))
+    }
 }
 
-pub fn part2(input: &str) -> u64 {
-    let (steps, map) = parse_input(input);
-
-    let starts = map
-        .iter()
-        .filter(|(point, _)| point.ends_with('A'))
-        .map(|(point, _)| point.to_owned())
-        .collect::<Vec<String>>();
-
-    starts
-        .iter()
-        .map(|start| count_steps(start, false, &steps, &map))
-        .unique()
-        .reduce(|ans, count| lcm(ans, count))
-        .unwrap()
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reports_ghosts_that_never_line_up() {
+        // the first ghost is on Z after odd steps, the second after even ones
+        let input = "L\n\n11A = (11Z, 11Z)\n11Z = (11A, 11A)\n22A = (22B, 22B)\n22B = (22Z, 22Z)\n22Z = (22B, 22B)";
+        let map = Day8::parse(input).unwrap();
+
+        assert_eq!(
+            Day8::part2(&map),
+            Err(SolveError::Failed(
+                "ghosts never all reach Z together".to_owned()
+            ))
+        );
+    }
 }
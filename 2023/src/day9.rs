@@ -1,15 +1,6 @@
-fn parse_input(input: &str) -> Vec<Vec<i32>> {
-    input
-        .lines()
-        .map(|line| {
-            line.split_whitespace()
-                .map(|num| num.parse::<i32>().unwrap())
-                .collect()
-        })
-        .collect()
-}
+use aoc_core::{Answer, Solution, SolveError};
 
-fn calc_next_sequence(sequence: &Vec<i32>) -> Vec<i32> {
+fn calc_next_sequence(sequence: &[i32]) -> Vec<i32> {
     let mut next_sequence = Vec::new();
     sequence.iter().enumerate().skip(1).for_each(|(idx, val)| {
         let prev = sequence.get(idx - 1).unwrap();
@@ -19,42 +10,55 @@ fn calc_next_sequence(sequence: &Vec<i32>) -> Vec<i32> {
     next_sequence
 }
 
-fn find_next(sequence: &Vec<i32>) -> i32 {
+fn find_next(sequence: &[i32]) -> i32 {
     let next_sequence = calc_next_sequence(sequence);
 
     if next_sequence.iter().all(|v| *v == 0) {
         return *sequence.last().unwrap();
     }
 
-    return sequence.last().unwrap() + find_next(&next_sequence);
+    sequence.last().unwrap() + find_next(&next_sequence)
 }
 
-fn find_prev(sequence: &Vec<i32>) -> i32 {
+fn find_prev(sequence: &[i32]) -> i32 {
     let next_sequence = calc_next_sequence(sequence);
 
     if next_sequence.iter().all(|v| *v == 0) {
         return *sequence.first().unwrap();
     }
 
-    return sequence.first().unwrap() - find_prev(&next_sequence);
+    sequence.first().unwrap() - find_prev(&next_sequence)
 }
 
-pub fn part1(input: &str) -> i32 {
-    let sequences = parse_input(input);
+pub struct Day9;
 
-    sequences
-        .iter()
-        .map(find_next)
-        .reduce(|sum, val| val + sum)
-        .unwrap()
-}
+impl Solution for Day9 {
+    type Input = Vec<Vec<i32>>;
+
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        Ok(input
+            .lines()
+            .map(|line| {
+                line.split_whitespace()
+                    .map(|num| num.parse::<i32>().unwrap())
+                    .collect()
+            })
+            .collect())
+    }
 
-pub fn part2(input: &str) -> i32 {
-    let sequences = parse_input(input);
+    fn part1(sequences: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(sequences
+            .iter()
+            .map(|sequence| find_next(sequence))
+            .sum::<i32>()
+            .into())
+    }
 
-    sequences
-        .iter()
-        .map(find_prev)
-        .reduce(|sum, val| val + sum)
-        .unwrap()
+    fn part2(sequences: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(sequences
+            .iter()
+            .map(|sequence| find_prev(sequence))
+            .sum::<i32>()
+            .into())
+    }
 }
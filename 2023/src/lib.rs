@@ -33,30 +33,59 @@ macro_rules! oof {
     };
 }
 
-#[macro_export]
-macro_rules! build_runner_fn {
-    ($($d:literal => $module:ident),+) => {
-      $(
-        use aoc2023::$module;
-      )+
-
-      fn run(day: u8, part: u8) {
-        let path = format!("./input/day{}.txt", day);
-        let input = std::fs::read_to_string(path).expect("could not read input file");
+aoc_core::register_days!(2023;
+    1 => day1::Day1,
+    2 => day2::Day2,
+    3 => day3::Day3,
+    4 => day4::Day4,
+    5 => day5::Day5,
+    6 => day6::Day6,
+    7 => day7::Day7,
+    8 => day8::Day8,
+    9 => day9::Day9,
+    10 => day10::Day10,
+    11 => day11::Day11,
+    12 => day12::Day12,
+    13 => day13::Day13,
+    14 => day14::Day14,
+    15 => day15::Day15,
+    16 => day16::Day16,
+    17 => day17::Day17,
+    18 => day18::Day18,
+    19 => day19::Day19,
+    20 => day20::Day20,
+    21 => day21::Day21,
+    22 => day22::Day22,
+    23 => day23::Day23,
+    24 => day24::Day24,
+    25 => day25::Day25,
+);
 
-        match day {
-            $(
-              $d if part == 1 => {
-                  let sol = $module::part1(&input);
-                  println!("{}", sol);
-              },
-              $d if part == 2 => {
-                  let sol = $module::part2(&input);
-                  println!("{}", sol);
-              },
-            )+
-            _ => panic!("invalid day or part")
-        }
-      }
-    };
-}
+// day 21 walks 64 and 26501365 steps and day 24 part 1 uses the real test area, so their examples
+// are checked in the days themselves
+aoc_core::example_tests!(
+    day1::Day1 { part1 => 142, part2 in "day1-2.txt" => 281 },
+    day2::Day2 { part1 => 8, part2 => 2286 },
+    day3::Day3 { part1 => 4361, part2 => 467835 },
+    day4::Day4 { part1 => 13, part2 => 30 },
+    day5::Day5 { part1 => 35, part2 => 46 },
+    day6::Day6 { part1 => 288, part2 => 71503 },
+    day7::Day7 { part1 => 6440, part2 => 5905 },
+    day8::Day8 { part1 => 6, part2 in "day8-2.txt" => 6 },
+    day9::Day9 { part1 => 114, part2 => 2 },
+    day10::Day10 { part1 => 8, part2 in "day10-2.txt" => 10 },
+    day11::Day11 { part1 => 374, part2 => 82000210 },
+    day12::Day12 { part1 => 21, part2 => 525152 },
+    day13::Day13 { part1 => 405, part2 => 400 },
+    day14::Day14 { part1 => 136, part2 => 64 },
+    day15::Day15 { part1 => 1320, part2 => 145 },
+    day16::Day16 { part1 => 46, part2 => 51 },
+    day17::Day17 { part1 => 102, part2 => 94 },
+    day18::Day18 { part1 => 62, part2 => 952408144115_u64 },
+    day19::Day19 { part1 => 19114, part2 => 167409079868000_u64 },
+    day20::Day20 { part1 => 32000000, part2 in "day20-counters.txt" => 12673 },
+    day22::Day22 { part1 => 5, part2 => 7 },
+    day23::Day23 { part1 => 94, part2 => 154 },
+    day24::Day24 { part2 => 47 },
+    day25::Day25 { part1 => 54 },
+);
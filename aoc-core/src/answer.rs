@@ -0,0 +1,124 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+/// Serialized untagged, so numbers, strings and grids show up as plain JSON values.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Answer {
+    Int(i64),
+    Text(String),
+    /// Multi-line art, one string per row.
+    Grid(Vec<String>),
+}
+
+impl Answer {
+    /// Builds a grid answer, dropping blank rows around it and trailing whitespace on each row
+    /// so differently printed versions of the same picture compare equal.
+    pub fn grid<I, S>(rows: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut rows: Vec<String> = rows
+            .into_iter()
+            .map(|row| row.as_ref().trim_end().to_owned())
+            .skip_while(|row| row.is_empty())
+            .collect();
+
+        while rows.last().is_some_and(|row| row.is_empty()) {
+            rows.pop();
+        }
+
+        Self::Grid(rows)
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Int(num) => write!(f, "{num}"),
+            Self::Text(text) => write!(f, "{text}"),
+            Self::Grid(rows) => write!(f, "{}", rows.join("\n")),
+        }
+    }
+}
+
+/// Numbers too large for an `i64` become text answers, which still print and compare the same.
+macro_rules! impl_from_int {
+    ($($t:ty),+) => {
+        $(
+          impl From<$t> for Answer {
+              fn from(num: $t) -> Self {
+                  i64::try_from(num).map_or_else(|_| Self::Text(num.to_string()), Self::Int)
+              }
+          }
+        )+
+    };
+}
+
+impl_from_int!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Self::Text(text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        Self::Text(String::from(text))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_convert_numbers() {
+        assert_eq!(Answer::from(42_u8), Answer::Int(42));
+        assert_eq!(Answer::from(-42_isize), Answer::Int(-42));
+        assert_eq!(
+            Answer::from(1_000_000_000_000_u64),
+            Answer::Int(1_000_000_000_000)
+        );
+    }
+
+    #[test]
+    fn can_convert_large_numbers_to_text() {
+        assert_eq!(
+            Answer::from(u64::MAX),
+            Answer::Text(String::from("18446744073709551615"))
+        );
+        assert_eq!(Answer::from(i128::MIN).to_string(), i128::MIN.to_string());
+    }
+
+    #[test]
+    fn can_display() {
+        assert_eq!(Answer::Int(-7).to_string(), "-7");
+        assert_eq!(Answer::from("CMZ").to_string(), "CMZ");
+        assert_eq!(Answer::grid(["#.", ".#"]).to_string(), "#.\n.#");
+    }
+
+    #[test]
+    fn can_normalize_grids() {
+        assert_eq!(
+            Answer::grid("\n#..  \n.#.\n\n".lines()),
+            Answer::Grid(vec![String::from("#.."), String::from(".#.")])
+        );
+    }
+
+    #[test]
+    fn can_serialize() {
+        let answers = vec![
+            Answer::Int(42),
+            Answer::from("CMZ"),
+            Answer::grid(["#.", ".#"]),
+        ];
+        let json = serde_json::to_string(&answers).unwrap();
+
+        assert_eq!(json, r##"[42,"CMZ",["#.",".#"]]"##);
+        assert_eq!(serde_json::from_str::<Vec<Answer>>(&json).unwrap(), answers);
+    }
+}
@@ -0,0 +1,232 @@
+use std::{
+    collections::{HashMap, HashSet},
+    hash::Hash,
+};
+
+use crate::math;
+
+/// A repeating stretch of a simulation: the state after `start + length` steps is the same as
+/// the one after `start` steps, so everything from `start` on repeats every `length` steps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    pub start: usize,
+    pub length: usize,
+}
+
+impl Cycle {
+    /// The step before the first repetition that has the same state as step `n`.
+    pub fn equivalent(&self, n: usize) -> usize {
+        if n < self.start {
+            n
+        } else {
+            self.start + (n - self.start) % self.length
+        }
+    }
+
+    /// How many whole cycles lie between `n` and its equivalent step.
+    pub fn skipped(&self, n: usize) -> usize {
+        n.saturating_sub(self.start) / self.length
+    }
+
+    /// The value of a metric after `n` steps, given its values after each of the steps
+    /// `0..=start + length`. Metrics that keep growing, like a height, are assumed to gain the
+    /// same amount every cycle.
+    pub fn extrapolate(&self, n: usize, values: &[i64]) -> i64 {
+        let gain = values[self.start + self.length] - values[self.start];
+        values[self.equivalent(n)] + self.skipped(n) as i64 * gain
+    }
+}
+
+/// Steps `state` until the key of a state repeats and returns the cycle found. `key` is called
+/// once for every state, in order, starting with the initial one. When this returns, `state` has
+/// been stepped `start + length` times.
+pub fn find_cycle<S, K>(
+    state: &mut S,
+    mut step: impl FnMut(&mut S),
+    mut key: impl FnMut(&S) -> K,
+) -> Cycle
+where
+    K: Eq + Hash,
+{
+    let mut seen = HashMap::new();
+
+    for n in 0.. {
+        if let Some(start) = seen.insert(key(state), n) {
+            return Cycle {
+                start,
+                length: n - start,
+            };
+        }
+
+        step(state);
+    }
+
+    unreachable!()
+}
+
+/// The value of `metric` after `steps` steps of a simulation that eventually repeats, without
+/// running all of them.
+pub fn fast_forward<S, K>(
+    mut state: S,
+    steps: usize,
+    step: impl FnMut(&mut S),
+    mut key: impl FnMut(&S) -> K,
+    mut metric: impl FnMut(&S) -> i64,
+) -> i64
+where
+    K: Eq + Hash,
+{
+    let mut values = Vec::new();
+    let cycle = find_cycle(&mut state, step, |state| {
+        values.push(metric(state));
+        key(state)
+    });
+
+    match values.get(steps) {
+        Some(value) => *value,
+        None => cycle.extrapolate(steps, &values),
+    }
+}
+
+/// The steps something happens on in a simulation that eventually repeats: the ones before the
+/// cycle starts, and the ones in its first pass, which recur every `length` steps after that.
+#[derive(Debug, Clone)]
+pub struct Schedule {
+    transient: HashSet<usize>,
+    residues: Vec<usize>,
+    cycle: Cycle,
+}
+
+impl Schedule {
+    /// `times` are the steps before `cycle.start + cycle.length` the event happened on.
+    pub fn new(cycle: Cycle, times: impl IntoIterator<Item = usize>) -> Self {
+        let (transient, residues): (Vec<_>, Vec<_>) =
+            times.into_iter().partition(|time| *time < cycle.start);
+
+        Schedule {
+            transient: transient.into_iter().collect(),
+            residues,
+            cycle,
+        }
+    }
+
+    pub fn cycle(&self) -> Cycle {
+        self.cycle
+    }
+
+    pub fn happens(&self, n: usize) -> bool {
+        if n < self.cycle.start {
+            self.transient.contains(&n)
+        } else {
+            self.residues.contains(&self.cycle.equivalent(n))
+        }
+    }
+}
+
+/// The first step every schedule's event happens on, found with the CRT once all of them are
+/// in their cycles. `None` if they never line up or there are no schedules.
+pub fn first_common(schedules: &[Schedule]) -> Option<usize> {
+    let settled = schedules
+        .iter()
+        .map(|schedule| schedule.cycle.start)
+        .max()?;
+    if let Some(n) = (0..settled).find(|n| schedules.iter().all(|s| s.happens(*n))) {
+        return Some(n);
+    }
+
+    // every combination of residues, merged one schedule at a time
+    let combined = schedules.iter().fold(vec![(0, 1)], |combined, schedule| {
+        combined
+            .iter()
+            .flat_map(|congruence| {
+                schedule.residues.iter().filter_map(|residue| {
+                    math::crt([
+                        *congruence,
+                        (*residue as i128, schedule.cycle.length as i128),
+                    ])
+                })
+            })
+            .collect()
+    });
+
+    combined
+        .into_iter()
+        .filter_map(|congruence| math::crt_at_least([congruence], settled as i128))
+        .min()
+        .map(|n| n as usize)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_find_cycle() {
+        // 2 -> 4 -> 16 -> 56 -> 36 -> 96 -> 16
+        let mut value = 2u64;
+        let cycle = find_cycle(
+            &mut value,
+            |value| *value = *value * *value % 100,
+            |value| *value,
+        );
+
+        assert_eq!(
+            cycle,
+            Cycle {
+                start: 2,
+                length: 4
+            }
+        );
+        assert_eq!(value, 16);
+        assert_eq!(cycle.equivalent(1), 1);
+        assert_eq!(cycle.equivalent(11), 3);
+        assert_eq!(cycle.skipped(10), 2);
+    }
+
+    #[test]
+    fn can_fast_forward() {
+        // position on a track of 5 after a run-up of 3, plus the distance covered
+        let step = |(pos, dist): &mut (u64, i64)| {
+            *pos = if *pos < 7 { *pos + 1 } else { 3 };
+            *dist += 1;
+        };
+
+        let distance = fast_forward(
+            (0, 0),
+            1_000_000_000_000,
+            step,
+            |(pos, _)| *pos,
+            |(_, dist)| *dist,
+        );
+        assert_eq!(distance, 1_000_000_000_000);
+
+        let pos = fast_forward(
+            (0, 0),
+            1_000_000_000_000,
+            step,
+            |(pos, _)| *pos,
+            |(pos, _)| *pos as i64,
+        );
+        assert_eq!(pos, 3 + (1_000_000_000_000 - 3) % 5);
+        assert_eq!(
+            fast_forward((0, 0), 2, step, |(pos, _)| *pos, |(pos, _)| *pos as i64),
+            2
+        );
+    }
+
+    #[test]
+    fn can_line_up_schedules() {
+        let cycle = |start, length| Cycle { start, length };
+
+        // 1, 4, 9, 14, ... and 2, 5, 8, 11, 14, ...
+        let a = Schedule::new(cycle(2, 5), [1, 4]);
+        let b = Schedule::new(cycle(0, 3), [2]);
+        assert!(a.happens(1) && !a.happens(2) && a.happens(9));
+        assert_eq!(first_common(&[a.clone(), b]), Some(14));
+
+        let never = Schedule::new(cycle(0, 4), []);
+        assert_eq!(first_common(&[a.clone(), never]), None);
+        assert_eq!(first_common(&[a]), Some(1));
+        assert_eq!(first_common(&[]), None);
+    }
+}
@@ -0,0 +1,287 @@
+use std::{
+    collections::{BTreeSet, HashMap},
+    fmt::Display,
+    ops,
+};
+
+use crate::math::Rational;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Op {
+    Add,
+    Sub,
+    Mul,
+    Div,
+}
+
+impl Op {
+    pub fn parse(input: &str) -> Option<Self> {
+        match input {
+            "+" => Some(Self::Add),
+            "-" => Some(Self::Sub),
+            "*" => Some(Self::Mul),
+            "/" => Some(Self::Div),
+            _ => None,
+        }
+    }
+
+    pub fn apply(&self, lhs: Rational, rhs: Rational) -> Result<Rational, Error> {
+        if *self == Self::Div && rhs.is_zero() {
+            return Err(Error::DivisionByZero);
+        }
+
+        match self {
+            Self::Add => lhs.checked_add(rhs),
+            Self::Sub => lhs.checked_sub(rhs),
+            Self::Mul => lhs.checked_mul(rhs),
+            Self::Div => lhs.checked_div(rhs),
+        }
+        .ok_or(Error::Overflow)
+    }
+}
+
+impl Display for Op {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Add => write!(f, "+"),
+            Self::Sub => write!(f, "-"),
+            Self::Mul => write!(f, "*"),
+            Self::Div => write!(f, "/"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    UnknownVariable(String),
+    DivisionByZero,
+    /// The variable is multiplied with itself or divided by.
+    NonLinear(String),
+    /// Both sides change at the same rate but never meet.
+    NoSolution,
+    /// Both sides are the same for every value.
+    Indeterminate,
+    /// A value doesn't fit in a [`Rational`].
+    Overflow,
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnknownVariable(var) => write!(f, "no value for {var}"),
+            Self::DivisionByZero => write!(f, "division by zero"),
+            Self::NonLinear(var) => write!(f, "not linear in {var}"),
+            Self::NoSolution => write!(f, "no value solves the equation"),
+            Self::Indeterminate => write!(f, "every value solves the equation"),
+            Self::Overflow => write!(f, "a value is too large to represent"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Arithmetic expression tree over exact fractions and named variables.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Expr {
+    Num(Rational),
+    Var(String),
+    Binary(Box<Expr>, Op, Box<Expr>),
+}
+
+impl Expr {
+    pub fn num(num: i64) -> Self {
+        Self::Num(num.into())
+    }
+
+    pub fn var(name: &str) -> Self {
+        Self::Var(name.to_owned())
+    }
+
+    pub fn binary(lhs: Expr, op: Op, rhs: Expr) -> Self {
+        Self::Binary(Box::new(lhs), op, Box::new(rhs))
+    }
+
+    pub fn variables(&self) -> BTreeSet<&str> {
+        match self {
+            Self::Num(_) => BTreeSet::new(),
+            Self::Var(var) => BTreeSet::from([var.as_str()]),
+            Self::Binary(lhs, _, rhs) => &lhs.variables() | &rhs.variables(),
+        }
+    }
+
+    pub fn substitute(&self, var: &str, value: &Expr) -> Expr {
+        match self {
+            Self::Var(name) if name == var => value.clone(),
+            Self::Binary(lhs, op, rhs) => {
+                Self::binary(lhs.substitute(var, value), *op, rhs.substitute(var, value))
+            }
+            _ => self.clone(),
+        }
+    }
+
+    pub fn eval(&self, vars: &HashMap<&str, Rational>) -> Result<Rational, Error> {
+        match self {
+            Self::Num(num) => Ok(*num),
+            Self::Var(var) => vars
+                .get(var.as_str())
+                .copied()
+                .ok_or_else(|| Error::UnknownVariable(var.clone())),
+            Self::Binary(lhs, op, rhs) => op.apply(lhs.eval(vars)?, rhs.eval(vars)?),
+        }
+    }
+
+    /// Folds constant subtrees and drops additions of 0 and multiplications by 1. Divisions by
+    /// zero are kept as they are.
+    pub fn simplify(&self) -> Expr {
+        let Self::Binary(lhs, op, rhs) = self else {
+            return self.clone();
+        };
+
+        let (lhs, rhs) = (lhs.simplify(), rhs.simplify());
+        let zero = |expr: &Expr| matches!(expr, Self::Num(num) if num.is_zero());
+        let one = |expr: &Expr| matches!(expr, Self::Num(num) if *num == Rational::ONE);
+
+        match (lhs, *op, rhs) {
+            (Self::Num(a), op, Self::Num(b)) if op.apply(a, b).is_ok() => {
+                Self::Num(op.apply(a, b).unwrap())
+            }
+            (lhs, Op::Add | Op::Sub, rhs) if zero(&rhs) => lhs,
+            (lhs, Op::Mul | Op::Div, rhs) if one(&rhs) => lhs,
+            (lhs, Op::Add, rhs) if zero(&lhs) => rhs,
+            (lhs, Op::Mul, rhs) if one(&lhs) => rhs,
+            (lhs, Op::Mul, rhs) if zero(&lhs) || zero(&rhs) => Self::Num(Rational::ZERO),
+            (lhs, op, rhs) => Self::binary(lhs, op, rhs),
+        }
+    }
+
+    /// Writes the expression as `a * var + b`, returning `(a, b)`.
+    pub fn linear(&self, var: &str) -> Result<(Rational, Rational), Error> {
+        match self {
+            Self::Num(num) => Ok((Rational::ZERO, *num)),
+            Self::Var(name) if name == var => Ok((Rational::ONE, Rational::ZERO)),
+            Self::Var(name) => Err(Error::UnknownVariable(name.clone())),
+            Self::Binary(lhs, op, rhs) => {
+                let ((a, b), (c, d)) = (lhs.linear(var)?, rhs.linear(var)?);
+
+                match op {
+                    Op::Add | Op::Sub => Ok((op.apply(a, c)?, op.apply(b, d)?)),
+                    Op::Mul if a.is_zero() => Ok((op.apply(b, c)?, op.apply(b, d)?)),
+                    Op::Mul | Op::Div if c.is_zero() => Ok((op.apply(a, d)?, op.apply(b, d)?)),
+                    Op::Mul | Op::Div => Err(Error::NonLinear(var.to_owned())),
+                }
+            }
+        }
+    }
+
+    /// The value of `var` that makes `lhs` equal `rhs`, the variable can be on both sides but
+    /// every other one has to be substituted away first.
+    pub fn solve(lhs: &Expr, rhs: &Expr, var: &str) -> Result<Rational, Error> {
+        let ((a, b), (c, d)) = (lhs.linear(var)?, rhs.linear(var)?);
+
+        let rate = Op::Sub.apply(a, c)?;
+        match (rate.is_zero(), d == b) {
+            (false, _) => Op::Div.apply(Op::Sub.apply(d, b)?, rate),
+            (true, true) => Err(Error::Indeterminate),
+            (true, false) => Err(Error::NoSolution),
+        }
+    }
+}
+
+impl From<Rational> for Expr {
+    fn from(num: Rational) -> Self {
+        Self::Num(num)
+    }
+}
+
+impl From<i64> for Expr {
+    fn from(num: i64) -> Self {
+        Self::num(num)
+    }
+}
+
+macro_rules! impl_op {
+    ($($trait:ident::$fn:ident => $op:ident),+) => {
+        $(
+          impl ops::$trait for Expr {
+              type Output = Expr;
+
+              fn $fn(self, rhs: Expr) -> Expr {
+                  Expr::binary(self, Op::$op, rhs)
+              }
+          }
+        )+
+    };
+}
+
+impl_op!(Add::add => Add, Sub::sub => Sub, Mul::mul => Mul, Div::div => Div);
+
+impl Display for Expr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Num(num) => write!(f, "{num}"),
+            Self::Var(var) => write!(f, "{var}"),
+            Self::Binary(lhs, op, rhs) => write!(f, "({lhs} {op} {rhs})"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_simplify_and_eval() {
+        let expr = (Expr::num(4) + Expr::num(2)) * Expr::var("x") / (Expr::num(3) - Expr::num(2));
+
+        assert_eq!(expr.to_string(), "(((4 + 2) * x) / (3 - 2))");
+        assert_eq!(expr.simplify().to_string(), "(6 * x)");
+        assert_eq!(
+            expr.eval(&HashMap::from([("x", Rational::new(1, 4).unwrap())])),
+            Ok(Rational::new(3, 2).unwrap())
+        );
+        assert_eq!(
+            (Expr::var("x") + Expr::var("y")).variables(),
+            BTreeSet::from(["x", "y"])
+        );
+        assert_eq!(
+            expr.eval(&HashMap::new()),
+            Err(Error::UnknownVariable("x".to_owned()))
+        );
+        assert_eq!(
+            (Expr::num(1) / Expr::num(0)).simplify().to_string(),
+            "(1 / 0)"
+        );
+    }
+
+    #[test]
+    fn can_solve_with_variable_on_both_sides() {
+        // (x + 3) / 2 = 10 - x
+        let lhs = (Expr::var("x") + Expr::num(3)) / Expr::num(2);
+        let rhs = Expr::num(10) - Expr::var("x");
+        assert_eq!(
+            Expr::solve(&lhs, &rhs, "x"),
+            Ok(Rational::new(17, 3).unwrap())
+        );
+
+        let square = Expr::var("x") * Expr::var("x");
+        assert_eq!(
+            Expr::solve(&square, &Expr::num(4), "x"),
+            Err(Error::NonLinear("x".to_owned()))
+        );
+        assert_eq!(
+            Expr::solve(&Expr::var("x"), &(Expr::var("x") + Expr::num(1)), "x"),
+            Err(Error::NoSolution)
+        );
+        assert_eq!(
+            Expr::solve(&Expr::var("x"), &Expr::var("x"), "x"),
+            Err(Error::Indeterminate)
+        );
+
+        let huge = Expr::num(i64::MAX) * Expr::num(i64::MAX) * Expr::num(i64::MAX);
+        assert_eq!(huge.eval(&HashMap::new()), Err(Error::Overflow));
+        assert_eq!(
+            Expr::solve(&(huge * Expr::var("x")), &Expr::num(1), "x"),
+            Err(Error::Overflow)
+        );
+    }
+}
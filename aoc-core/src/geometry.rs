@@ -0,0 +1,451 @@
+use std::ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign};
+
+/// One of the four cardinal directions. North is up, towards the first row of a grid.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub enum Direction {
+    N,
+    E,
+    S,
+    W,
+}
+
+impl Direction {
+    /// Clockwise, starting from north.
+    pub const ALL: [Self; 4] = [Self::N, Self::E, Self::S, Self::W];
+
+    /// Reads `N`/`E`/`S`/`W`, `U`/`R`/`D`/`L` or the arrows `^`/`>`/`v`/`<`.
+    pub fn parse(c: char) -> Option<Self> {
+        match c {
+            'N' | 'U' | '^' => Some(Self::N),
+            'E' | 'R' | '>' => Some(Self::E),
+            'S' | 'D' | 'v' => Some(Self::S),
+            'W' | 'L' | '<' => Some(Self::W),
+            _ => None,
+        }
+    }
+
+    /// The `(row, column)` step, as taken by [`Grid::offset`](crate::Grid::offset).
+    pub fn delta(self) -> (isize, isize) {
+        match self {
+            Self::N => (-1, 0),
+            Self::E => (0, 1),
+            Self::S => (1, 0),
+            Self::W => (0, -1),
+        }
+    }
+
+    pub fn turn_left(self) -> Self {
+        match self {
+            Self::N => Self::W,
+            Self::E => Self::N,
+            Self::S => Self::E,
+            Self::W => Self::S,
+        }
+    }
+
+    pub fn turn_right(self) -> Self {
+        match self {
+            Self::N => Self::E,
+            Self::E => Self::S,
+            Self::S => Self::W,
+            Self::W => Self::N,
+        }
+    }
+
+    pub fn opposite(self) -> Self {
+        self.turn_left().turn_left()
+    }
+
+    pub fn is_vertical(self) -> bool {
+        matches!(self, Self::N | Self::S)
+    }
+}
+
+/// The eight compass directions, for when diagonal steps count too.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub enum Compass {
+    N,
+    NE,
+    E,
+    SE,
+    S,
+    SW,
+    W,
+    NW,
+}
+
+impl Compass {
+    /// Clockwise, starting from north.
+    pub const ALL: [Self; 8] = [
+        Self::N,
+        Self::NE,
+        Self::E,
+        Self::SE,
+        Self::S,
+        Self::SW,
+        Self::W,
+        Self::NW,
+    ];
+
+    /// The `(row, column)` step, as taken by [`Grid::offset`](crate::Grid::offset).
+    pub fn delta(self) -> (isize, isize) {
+        let row = match self {
+            Self::N | Self::NE | Self::NW => -1,
+            Self::S | Self::SE | Self::SW => 1,
+            Self::E | Self::W => 0,
+        };
+        let col = match self {
+            Self::W | Self::NW | Self::SW => -1,
+            Self::E | Self::NE | Self::SE => 1,
+            Self::N | Self::S => 0,
+        };
+
+        (row, col)
+    }
+
+    /// Turns 45 degrees clockwise.
+    pub fn rotate_cw(self) -> Self {
+        Self::ALL[(self as usize + 1) % 8]
+    }
+
+    /// Turns 45 degrees counterclockwise.
+    pub fn rotate_ccw(self) -> Self {
+        Self::ALL[(self as usize + 7) % 8]
+    }
+
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self as usize + 4) % 8]
+    }
+}
+
+impl From<Direction> for Compass {
+    fn from(direction: Direction) -> Self {
+        match direction {
+            Direction::N => Self::N,
+            Direction::E => Self::E,
+            Direction::S => Self::S,
+            Direction::W => Self::W,
+        }
+    }
+}
+
+/// The six neighbours on a hex grid with flat topped cells, see [`Point2::hex_distance`] for the
+/// axial coordinates they step in.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub enum Hex {
+    N,
+    NE,
+    SE,
+    S,
+    SW,
+    NW,
+}
+
+impl Hex {
+    /// Clockwise, starting from north.
+    pub const ALL: [Self; 6] = [Self::N, Self::NE, Self::SE, Self::S, Self::SW, Self::NW];
+
+    pub fn rotate_cw(self) -> Self {
+        Self::ALL[(self as usize + 1) % 6]
+    }
+
+    pub fn rotate_ccw(self) -> Self {
+        Self::ALL[(self as usize + 5) % 6]
+    }
+
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self as usize + 3) % 6]
+    }
+}
+
+fn abs_diff<T: Copy + PartialOrd + Sub<Output = T>>(a: T, b: T) -> T {
+    if a > b {
+        a - b
+    } else {
+        b - a
+    }
+}
+
+/// A point or vector in the plane, `y` grows downwards like the rows of a grid.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default)]
+pub struct Point2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Point2<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Point2 { x, y }
+    }
+}
+
+impl<T: Copy + PartialOrd + Add<Output = T> + Sub<Output = T>> Point2<T> {
+    pub fn manhattan(&self, other: &Self) -> T {
+        abs_diff(self.x, other.x) + abs_diff(self.y, other.y)
+    }
+
+    pub fn chebyshev(&self, other: &Self) -> T {
+        let (dx, dy) = (abs_diff(self.x, other.x), abs_diff(self.y, other.y));
+        if dx > dy {
+            dx
+        } else {
+            dy
+        }
+    }
+
+    /// Distance on a hex grid, where `x` and `y` are the axial coordinates `q` and `r`.
+    pub fn hex_distance(&self, other: &Self) -> T
+    where
+        T: Div<Output = T> + From<i8>,
+    {
+        let dq = self.x - other.x;
+        let dr = self.y - other.y;
+        let zero = T::from(0);
+
+        (abs_diff(dq, zero) + abs_diff(dr, zero) + abs_diff(dq + dr, zero)) / T::from(2)
+    }
+}
+
+impl<T: Copy + Neg<Output = T>> Point2<T> {
+    /// Turns the vector 90 degrees clockwise, as seen with `y` pointing down.
+    pub fn rotate_cw(self) -> Self {
+        Point2::new(-self.y, self.x)
+    }
+
+    /// Turns the vector 90 degrees counterclockwise, as seen with `y` pointing down.
+    pub fn rotate_ccw(self) -> Self {
+        Point2::new(self.y, -self.x)
+    }
+}
+
+impl<T: Copy + Add<Output = T> + From<i8>> Point2<T> {
+    pub fn step(self, direction: Direction) -> Self {
+        self + Point2::from(direction)
+    }
+}
+
+/// The unit vector pointing that way.
+impl<T: From<i8>> From<Direction> for Point2<T> {
+    fn from(direction: Direction) -> Self {
+        Point2::from(Compass::from(direction))
+    }
+}
+
+/// The vector of a single step that way, diagonals included.
+impl<T: From<i8>> From<Compass> for Point2<T> {
+    fn from(direction: Compass) -> Self {
+        let (row, col) = direction.delta();
+        Point2::new(T::from(col as i8), T::from(row as i8))
+    }
+}
+
+/// The step in axial coordinates.
+impl<T: From<i8>> From<Hex> for Point2<T> {
+    fn from(direction: Hex) -> Self {
+        let (q, r) = match direction {
+            Hex::N => (0, -1),
+            Hex::NE => (1, -1),
+            Hex::SE => (1, 0),
+            Hex::S => (0, 1),
+            Hex::SW => (-1, 1),
+            Hex::NW => (-1, 0),
+        };
+
+        Point2::new(T::from(q), T::from(r))
+    }
+}
+
+impl<T: Add<Output = T>> Add for Point2<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Point2::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Point2<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Point2::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl<T: Copy + Mul<Output = T>> Mul<T> for Point2<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self {
+        Point2::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Point2<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Point2::new(-self.x, -self.y)
+    }
+}
+
+impl<T: Copy + Add<Output = T>> AddAssign for Point2<T> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<T: Copy + Sub<Output = T>> SubAssign for Point2<T> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default)]
+pub struct Point3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T> Point3<T> {
+    pub const fn new(x: T, y: T, z: T) -> Self {
+        Point3 { x, y, z }
+    }
+}
+
+impl<T: Copy + PartialOrd + Add<Output = T> + Sub<Output = T>> Point3<T> {
+    pub fn manhattan(&self, other: &Self) -> T {
+        abs_diff(self.x, other.x) + abs_diff(self.y, other.y) + abs_diff(self.z, other.z)
+    }
+
+    pub fn chebyshev(&self, other: &Self) -> T {
+        [abs_diff(self.y, other.y), abs_diff(self.z, other.z)]
+            .into_iter()
+            .fold(
+                abs_diff(self.x, other.x),
+                |max, d| if d > max { d } else { max },
+            )
+    }
+}
+
+impl<T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T>> Point3<T> {
+    pub fn sum(&self) -> T {
+        self.x + self.y + self.z
+    }
+
+    pub fn dot(&self, other: &Self) -> T {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    pub fn cross(&self, other: &Self) -> Self {
+        Point3::new(
+            self.y * other.z - self.z * other.y,
+            self.z * other.x - self.x * other.z,
+            self.x * other.y - self.y * other.x,
+        )
+    }
+}
+
+impl<T: Add<Output = T>> Add for Point3<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Point3::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Point3<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Point3::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
+    }
+}
+
+impl<T: Copy + Mul<Output = T>> Mul<T> for Point3<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self {
+        Point3::new(self.x * rhs, self.y * rhs, self.z * rhs)
+    }
+}
+
+impl<T: Copy + Div<Output = T>> Div<T> for Point3<T> {
+    type Output = Self;
+
+    fn div(self, rhs: T) -> Self {
+        Point3::new(self.x / rhs, self.y / rhs, self.z / rhs)
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Point3<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Point3::new(-self.x, -self.y, -self.z)
+    }
+}
+
+impl<T: Copy + Add<Output = T>> AddAssign for Point3<T> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<T: Copy + Sub<Output = T>> SubAssign for Point3<T> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_turn() {
+        assert_eq!(Direction::parse('L'), Some(Direction::W));
+        assert_eq!(Direction::parse('v'), Some(Direction::S));
+        assert_eq!(Direction::N.turn_right(), Direction::E);
+        assert_eq!(Direction::N.turn_left(), Direction::W);
+        assert_eq!(Direction::E.opposite(), Direction::W);
+        assert_eq!(Compass::N.rotate_ccw(), Compass::NW);
+        assert_eq!(Compass::SE.rotate_cw(), Compass::S);
+        assert_eq!(Compass::NE.opposite(), Compass::SW);
+        assert_eq!(Compass::SW.delta(), (1, -1));
+        assert_eq!(Hex::NW.rotate_cw(), Hex::N);
+        assert_eq!(Hex::N.opposite(), Hex::S);
+
+        for direction in Direction::ALL {
+            let unit = Point2::<i32>::from(direction);
+            assert_eq!(Point2::from(direction.turn_right()), unit.rotate_cw());
+            assert_eq!(Point2::from(direction.turn_left()), unit.rotate_ccw());
+        }
+    }
+
+    #[test]
+    fn can_measure_points() {
+        let a = Point2::new(1, 2);
+        let b = Point2::new(4, -2);
+
+        assert_eq!(a + b, Point2::new(5, 0));
+        assert_eq!(a - b, Point2::new(-3, 4));
+        assert_eq!(a * 3, Point2::new(3, 6));
+        assert_eq!(a.manhattan(&b), 7);
+        assert_eq!(a.chebyshev(&b), 4);
+        assert_eq!(a.step(Direction::N), Point2::new(1, 1));
+        assert_eq!(Point2::new(3u8, 9).manhattan(&Point2::new(5, 2)), 9);
+
+        let far = [Hex::NE, Hex::NE, Hex::SE, Hex::S]
+            .into_iter()
+            .fold(Point2::new(0, 0), |pos, step| pos + Point2::from(step));
+        assert_eq!(far.hex_distance(&Point2::new(0, 0)), 3);
+
+        let u = Point3::new(1.0, 0.0, 0.0);
+        let v = Point3::new(0.0, 1.0, 0.0);
+        assert_eq!(u.cross(&v), Point3::new(0.0, 0.0, 1.0));
+        assert_eq!(u.dot(&v), 0.0);
+        assert_eq!((u + v * 2.0).sum(), 3.0);
+        assert_eq!(Point3::new(1, 5, -3).chebyshev(&Point3::new(0, 0, 0)), 5);
+        assert_eq!(Point3::new(1, 5, -3).manhattan(&Point3::new(0, 0, 0)), 9);
+    }
+}
@@ -0,0 +1,342 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::atomic::{AtomicUsize, Ordering},
+    thread,
+};
+
+/// A global minimum cut of an undirected graph: how many edges cross it and which nodes end up
+/// on the same side as node 0.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cut {
+    pub size: usize,
+    pub side: Vec<usize>,
+}
+
+/// Unit capacity flow network where every undirected edge `i` is the arc pair `2i` and `2i + 1`.
+struct Network {
+    arcs: Vec<Vec<(usize, usize)>>,
+    flow: Vec<i8>,
+}
+
+impl Network {
+    fn new(nodes: usize, edges: &[(usize, usize)]) -> Self {
+        let mut arcs = vec![Vec::new(); nodes];
+        for (id, (a, b)) in edges.iter().enumerate() {
+            arcs[*a].push((*b, 2 * id));
+            arcs[*b].push((*a, 2 * id + 1));
+        }
+
+        Network {
+            arcs,
+            flow: vec![0; 2 * edges.len()],
+        }
+    }
+
+    /// Breadth first search over arcs with capacity left, returning the node and arc each node
+    /// was reached from. The source maps to itself.
+    fn residual(&self, source: usize) -> Vec<Option<(usize, usize)>> {
+        let mut via = vec![None; self.arcs.len()];
+        let mut queue = VecDeque::from([source]);
+        via[source] = Some((source, usize::MAX));
+
+        while let Some(node) = queue.pop_front() {
+            for (next, arc) in &self.arcs[node] {
+                if via[*next].is_none() && self.flow[*arc] < 1 {
+                    via[*next] = Some((node, *arc));
+                    queue.push_back(*next);
+                }
+            }
+        }
+
+        via
+    }
+
+    /// Pushes up to `limit` units from `source` to `sink` and returns how many made it.
+    fn max_flow(&mut self, source: usize, sink: usize, limit: usize) -> usize {
+        self.flow.fill(0);
+
+        for pushed in 0..limit {
+            let via = self.residual(source);
+            if via[sink].is_none() {
+                return pushed;
+            }
+
+            let mut node = sink;
+            while node != source {
+                let (prev, arc) = via[node].unwrap();
+                self.flow[arc] += 1;
+                self.flow[arc ^ 1] -= 1;
+                node = prev;
+            }
+        }
+
+        limit
+    }
+}
+
+/// Finds a smallest set of edges whose removal splits the graph in two, by taking the smallest
+/// maximum flow from node 0 to every other node. `None` with fewer than two nodes, as there is
+/// nothing to split.
+pub fn min_cut(nodes: usize, edges: &[(usize, usize)]) -> Option<Cut> {
+    if nodes < 2 {
+        return None;
+    }
+
+    let mut network = Network::new(nodes, edges);
+    let mut best: Option<Cut> = None;
+
+    for sink in 1..nodes {
+        // a flow as large as the best cut so far can't improve on it, so stop pushing there
+        let limit = best.as_ref().map_or(edges.len() + 1, |cut| cut.size);
+        let size = network.max_flow(0, sink, limit);
+        if size == limit {
+            continue;
+        }
+
+        let via = network.residual(0);
+        let side = (0..nodes).filter(|node| via[*node].is_some()).collect();
+        best = Some(Cut { size, side });
+    }
+
+    best
+}
+
+/// Directed graph of at most 64 nodes with weighted arcs, for finding longest simple paths. The
+/// nodes a path has visited fit in a `u64`.
+#[derive(Debug, Clone, Default)]
+pub struct PathGraph {
+    arcs: Vec<Vec<(usize, usize)>>,
+}
+
+impl PathGraph {
+    pub fn new(nodes: usize) -> Self {
+        assert!(nodes <= 64, "{nodes} nodes don't fit in a bitmask");
+
+        PathGraph {
+            arcs: vec![Vec::new(); nodes],
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.arcs.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.arcs.is_empty()
+    }
+
+    pub fn add_arc(&mut self, from: usize, to: usize, weight: usize) {
+        self.arcs[from].push((to, weight));
+    }
+
+    pub fn add_edge(&mut self, a: usize, b: usize, weight: usize) {
+        self.add_arc(a, b, weight);
+        self.add_arc(b, a, weight);
+    }
+
+    /// The longest path from `start` to `end` that visits every node at most once, `None` if
+    /// there is no path at all.
+    pub fn longest(&self, start: usize, end: usize) -> Option<usize> {
+        let search = LongestSearch::new(self, end);
+        search.run(search.root(start), &mut HashMap::new());
+        search.best()
+    }
+
+    /// Like `longest`, but the first few steps are taken up front and the branches they lead to
+    /// get explored on every available thread.
+    pub fn longest_parallel(&self, start: usize, end: usize) -> Option<usize> {
+        let search = LongestSearch::new(self, end);
+        let threads = thread::available_parallelism().map_or(1, usize::from);
+
+        let mut bounds = HashMap::new();
+        let mut branches = vec![search.root(start)];
+        while branches.len() < 8 * threads {
+            let expanded: Vec<Branch> = branches
+                .iter()
+                .flat_map(|branch| search.expand(*branch, &mut bounds))
+                .collect();
+            if expanded.is_empty() {
+                break;
+            }
+            branches = expanded;
+        }
+
+        let next = AtomicUsize::new(0);
+        thread::scope(|scope| {
+            for _ in 0..threads {
+                scope.spawn(|| {
+                    let mut bounds = HashMap::new();
+                    while let Some(branch) = branches.get(next.fetch_add(1, Ordering::Relaxed)) {
+                        search.run(*branch, &mut bounds);
+                    }
+                });
+            }
+        });
+
+        search.best()
+    }
+}
+
+/// A partial path: where it is, which nodes it went through and how long it is.
+#[derive(Debug, Clone, Copy)]
+struct Branch {
+    node: usize,
+    visited: u64,
+    length: usize,
+}
+
+/// The most a path could still add from a node given the nodes it visited, `None` if it can't
+/// reach the end anymore. Many orders of visiting the same nodes meet again at the same node, so
+/// this gets memoised per `(node, visited)`.
+type Bounds = HashMap<(usize, u64), Option<usize>>;
+
+/// How many bounds a search keeps before starting over. Nearly every state is new, so without a
+/// limit the memo grows with the whole search, while the orders that meet again are close by.
+const MAX_BOUNDS: usize = 1 << 16;
+
+struct LongestSearch<'a> {
+    arcs: &'a [Vec<(usize, usize)>],
+    end: usize,
+    /// Heaviest arc into every node, each node still to be visited adds at most that.
+    heaviest_in: Vec<usize>,
+    /// The only node leading to `end`, once there the path has to finish.
+    gate: Option<usize>,
+    /// Longest path so far plus one, 0 until one is found.
+    best: AtomicUsize,
+}
+
+impl<'a> LongestSearch<'a> {
+    fn new(graph: &'a PathGraph, end: usize) -> Self {
+        let mut heaviest_in = vec![0; graph.len()];
+        let mut into_end = Vec::new();
+        for (from, arcs) in graph.arcs.iter().enumerate() {
+            for (to, weight) in arcs {
+                heaviest_in[*to] = heaviest_in[*to].max(*weight);
+                if *to == end && !into_end.contains(&from) {
+                    into_end.push(from);
+                }
+            }
+        }
+
+        LongestSearch {
+            arcs: &graph.arcs,
+            end,
+            heaviest_in,
+            gate: (into_end.len() == 1).then(|| into_end[0]),
+            best: AtomicUsize::new(0),
+        }
+    }
+
+    fn best(&self) -> Option<usize> {
+        self.best.load(Ordering::Relaxed).checked_sub(1)
+    }
+
+    fn root(&self, start: usize) -> Branch {
+        Branch {
+            node: start,
+            visited: 1 << start,
+            length: 0,
+        }
+    }
+
+    /// Only the nodes still reachable without going through visited ones can add to the path,
+    /// each by at most its heaviest arc.
+    fn bound(&self, node: usize, visited: u64, bounds: &mut Bounds) -> Option<usize> {
+        if bounds.len() >= MAX_BOUNDS && !bounds.contains_key(&(node, visited)) {
+            bounds.clear();
+        }
+
+        *bounds.entry((node, visited)).or_insert_with(|| {
+            let (mut reached, mut stack, mut bound) = (visited, vec![node], 0);
+            while let Some(from) = stack.pop() {
+                // the path stops at the end, so nothing behind it counts
+                if from == self.end {
+                    continue;
+                }
+                for (to, _) in &self.arcs[from] {
+                    if reached & (1 << to) == 0 {
+                        reached |= 1 << to;
+                        bound += self.heaviest_in[*to];
+                        stack.push(*to);
+                    }
+                }
+            }
+
+            (node == self.end || reached & (1 << self.end) != 0).then_some(bound)
+        })
+    }
+
+    /// The branches one step further, recording the path if it has reached the end.
+    fn expand(&self, branch: Branch, bounds: &mut Bounds) -> Vec<Branch> {
+        if branch.node == self.end {
+            self.best.fetch_max(branch.length + 1, Ordering::Relaxed);
+            return Vec::new();
+        }
+        match self.bound(branch.node, branch.visited, bounds) {
+            Some(bound) if branch.length + bound >= self.best.load(Ordering::Relaxed) => {}
+            _ => return Vec::new(),
+        }
+
+        self.arcs[branch.node]
+            .iter()
+            .filter(|(next, _)| branch.visited & (1 << next) == 0)
+            .filter(|(next, _)| self.gate != Some(branch.node) || *next == self.end)
+            .map(|(next, weight)| Branch {
+                node: *next,
+                visited: branch.visited | (1 << next),
+                length: branch.length + weight,
+            })
+            .collect()
+    }
+
+    fn run(&self, branch: Branch, bounds: &mut Bounds) {
+        for next in self.expand(branch, bounds) {
+            self.run(next, bounds);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_cut_a_bridge() {
+        let edges = [(0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 5), (5, 3)];
+        let cut = min_cut(6, &edges).unwrap();
+
+        assert_eq!(cut.size, 1);
+        assert_eq!(cut.side, vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn can_cut_disconnected_graphs() {
+        let cut = min_cut(4, &[(0, 1), (2, 3)]).unwrap();
+
+        assert_eq!(cut.size, 0);
+        assert_eq!(cut.side, vec![0, 1]);
+        assert_eq!(min_cut(1, &[]), None);
+    }
+
+    #[test]
+    fn can_find_longest_path() {
+        let mut graph = PathGraph::new(6);
+        for (a, b, weight) in [
+            (0, 1, 2),
+            (1, 2, 3),
+            (1, 3, 1),
+            (2, 3, 4),
+            (3, 4, 2),
+            (2, 4, 9),
+        ] {
+            graph.add_edge(a, b, weight);
+        }
+        graph.add_arc(4, 5, 1);
+
+        // 0 - 1 - 3 - 2 - 4 - 5 beats the direct 0 - 1 - 2 - 4 - 5
+        assert_eq!(graph.longest(0, 5), Some(17));
+        assert_eq!(graph.longest_parallel(0, 5), Some(17));
+        assert_eq!(graph.longest(5, 0), None);
+        assert_eq!(graph.longest(3, 3), Some(0));
+    }
+}
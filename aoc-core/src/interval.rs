@@ -0,0 +1,371 @@
+use std::{
+    fmt::{self, Debug},
+    iter::{Product, Sum},
+    ops::{Add, Sub},
+};
+
+/// The half-open range `start..end`, empty when `end <= start`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Interval<T> {
+    start: T,
+    end: T,
+}
+
+impl<T: Copy + Ord> Interval<T> {
+    pub fn new(start: T, end: T) -> Self {
+        Interval { start, end }
+    }
+
+    pub fn start(&self) -> T {
+        self.start
+    }
+
+    pub fn end(&self) -> T {
+        self.end
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.end <= self.start
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.start <= value && value < self.end
+    }
+
+    /// Whether every value of `other` is in this interval, which holds for any empty `other`.
+    pub fn covers(&self, other: &Self) -> bool {
+        other.is_empty() || (self.start <= other.start && other.end <= self.end)
+    }
+
+    pub fn overlaps(&self, other: &Self) -> bool {
+        !self.intersection(other).is_empty()
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        Interval::new(self.start.max(other.start), self.end.min(other.end))
+    }
+
+    /// The parts below and from `at`, either of which may be empty.
+    pub fn split_at(&self, at: T) -> (Self, Self) {
+        let at = at.clamp(self.start, self.end.max(self.start));
+        (Interval::new(self.start, at), Interval::new(at, self.end))
+    }
+
+    /// The parts of this interval below and above `other`, either of which may be empty.
+    pub fn difference(&self, other: &Self) -> (Self, Self) {
+        if other.is_empty() {
+            return (*self, Interval::new(self.end, self.end));
+        }
+
+        (
+            Interval::new(self.start, self.end.min(other.start)),
+            Interval::new(self.start.max(other.end), self.end),
+        )
+    }
+}
+
+impl<T> Interval<T>
+where
+    T: Copy + Ord + Add<Output = T> + Sub<Output = T> + From<u8>,
+{
+    /// The range `first..=last`.
+    pub fn inclusive(first: T, last: T) -> Self {
+        Interval::new(first, last + T::from(1))
+    }
+
+    /// The last value in the interval, which must not be empty.
+    pub fn last(&self) -> T {
+        self.end - T::from(1)
+    }
+
+    pub fn len(&self) -> T {
+        if self.is_empty() {
+            T::from(0)
+        } else {
+            self.end - self.start
+        }
+    }
+
+    pub fn shift(&self, offset: T) -> Self {
+        Interval::new(self.start + offset, self.end + offset)
+    }
+}
+
+impl<T: Debug> Debug for Interval<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}..{:?}", self.start, self.end)
+    }
+}
+
+/// A set of values stored as sorted, disjoint and non-adjacent intervals.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct IntervalSet<T> {
+    intervals: Vec<Interval<T>>,
+}
+
+impl<T: Copy + Ord> IntervalSet<T> {
+    pub fn new() -> Self {
+        IntervalSet {
+            intervals: Vec::new(),
+        }
+    }
+
+    pub fn insert(&mut self, interval: Interval<T>) {
+        if interval.is_empty() {
+            return;
+        }
+
+        // everything touching the new interval gets merged into it
+        let first = self.intervals.partition_point(|i| i.end < interval.start);
+        let last = self.intervals.partition_point(|i| i.start <= interval.end);
+
+        let merged = match &self.intervals[first..last] {
+            [] => interval,
+            touching => Interval::new(
+                touching[0].start.min(interval.start),
+                touching[touching.len() - 1].end.max(interval.end),
+            ),
+        };
+
+        self.intervals.splice(first..last, [merged]);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        let idx = self.intervals.partition_point(|i| i.end <= value);
+        self.intervals.get(idx).is_some_and(|i| i.contains(value))
+    }
+
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &Interval<T>> {
+        self.intervals.iter()
+    }
+
+    pub fn min(&self) -> Option<T> {
+        self.intervals.first().map(|i| i.start)
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        let mut union = self.clone();
+        other.iter().for_each(|i| union.insert(*i));
+        union
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut intersection = Vec::new();
+        let (mut a, mut b) = (self.iter().peekable(), other.iter().peekable());
+
+        while let (Some(i), Some(j)) = (a.peek(), b.peek()) {
+            let common = i.intersection(j);
+            if !common.is_empty() {
+                intersection.push(common);
+            }
+
+            if i.end < j.end {
+                a.next();
+            } else {
+                b.next();
+            }
+        }
+
+        IntervalSet {
+            intervals: intersection,
+        }
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut difference = Vec::new();
+
+        for interval in self.iter() {
+            let mut rest = *interval;
+            for cut in other.iter().filter(|cut| cut.overlaps(interval)) {
+                let (below, above) = rest.difference(cut);
+                if !below.is_empty() {
+                    difference.push(below);
+                }
+                rest = above;
+            }
+
+            if !rest.is_empty() {
+                difference.push(rest);
+            }
+        }
+
+        IntervalSet {
+            intervals: difference,
+        }
+    }
+}
+
+impl<T> IntervalSet<T>
+where
+    T: Copy + Ord + Add<Output = T> + Sub<Output = T> + From<u8> + Sum,
+{
+    /// How many values are in the set.
+    pub fn len(&self) -> T {
+        self.iter().map(Interval::len).sum()
+    }
+}
+
+impl<T: Copy + Ord> Default for IntervalSet<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Copy + Ord> From<Interval<T>> for IntervalSet<T> {
+    fn from(interval: Interval<T>) -> Self {
+        let mut set = IntervalSet::new();
+        set.insert(interval);
+        set
+    }
+}
+
+impl<T: Copy + Ord> FromIterator<Interval<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Interval<T>>>(iter: I) -> Self {
+        let mut intervals: Vec<Interval<T>> = iter.into_iter().filter(|i| !i.is_empty()).collect();
+        intervals.sort_by_key(|i| i.start);
+
+        let mut merged: Vec<Interval<T>> = Vec::with_capacity(intervals.len());
+        for interval in intervals {
+            match merged.last_mut() {
+                Some(last) if interval.start <= last.end => last.end = last.end.max(interval.end),
+                _ => merged.push(interval),
+            }
+        }
+
+        IntervalSet { intervals: merged }
+    }
+}
+
+/// An axis aligned box in `N` dimensions, an interval along each axis.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Cuboid<T, const N: usize>(pub [Interval<T>; N]);
+
+impl<T: Copy + Ord, const N: usize> Cuboid<T, N> {
+    pub fn is_empty(&self) -> bool {
+        self.0.iter().any(Interval::is_empty)
+    }
+
+    pub fn contains(&self, point: [T; N]) -> bool {
+        self.0
+            .iter()
+            .zip(point)
+            .all(|(axis, value)| axis.contains(value))
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        Cuboid(std::array::from_fn(|axis| {
+            self.0[axis].intersection(&other.0[axis])
+        }))
+    }
+
+    /// The parts below and from `at` along `axis`, either of which may be empty.
+    pub fn split_at(&self, axis: usize, at: T) -> (Self, Self) {
+        let (below, above) = self.0[axis].split_at(at);
+        let (mut lower, mut upper) = (*self, *self);
+        lower.0[axis] = below;
+        upper.0[axis] = above;
+
+        (lower, upper)
+    }
+}
+
+impl<T, const N: usize> Cuboid<T, N>
+where
+    T: Copy + Ord + Add<Output = T> + Sub<Output = T> + From<u8> + Product,
+{
+    /// How many points are in the box.
+    pub fn volume(&self) -> T {
+        self.0.iter().map(Interval::len).product()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_compare_intervals() {
+        let a = Interval::inclusive(2, 8);
+        let b = Interval::new(5, 12);
+
+        assert_eq!(a.len(), 7);
+        assert_eq!(a.last(), 8);
+        assert!(a.overlaps(&b));
+        assert!(!a.covers(&b));
+        assert!(a.covers(&Interval::new(3, 5)));
+        assert_eq!(a.intersection(&b), Interval::new(5, 9));
+        assert!(a.intersection(&Interval::new(9, 12)).is_empty());
+        assert_eq!(a.split_at(4), (Interval::new(2, 4), Interval::new(4, 9)));
+        assert!(a.split_at(0).0.is_empty());
+        assert_eq!(
+            a.difference(&Interval::new(4, 6)),
+            (Interval::new(2, 4), Interval::new(6, 9))
+        );
+        assert_eq!(a.shift(-2), Interval::new(0, 7));
+    }
+
+    #[test]
+    fn can_combine_sets() {
+        let mut set = IntervalSet::new();
+        set.insert(Interval::new(10, 15));
+        set.insert(Interval::new(1, 3));
+        set.insert(Interval::new(3, 5));
+        set.insert(Interval::new(20, 22));
+
+        assert_eq!(
+            set.iter().copied().collect::<Vec<_>>(),
+            vec![
+                Interval::new(1, 5),
+                Interval::new(10, 15),
+                Interval::new(20, 22)
+            ]
+        );
+        assert_eq!(set.len(), 11);
+        assert!(set.contains(4) && !set.contains(5) && set.contains(21));
+
+        set.insert(Interval::new(4, 21));
+        assert_eq!(set, IntervalSet::from(Interval::new(1, 22)));
+
+        let a: IntervalSet<i32> = [Interval::new(0, 10), Interval::new(20, 30)]
+            .into_iter()
+            .collect();
+        let b: IntervalSet<i32> = [Interval::new(5, 25), Interval::new(28, 29)]
+            .into_iter()
+            .collect();
+
+        let union: IntervalSet<i32> = [Interval::new(0, 30)].into_iter().collect();
+        let intersection: IntervalSet<i32> = [(5, 10), (20, 25), (28, 29)]
+            .into_iter()
+            .map(|(s, e)| Interval::new(s, e))
+            .collect();
+        let difference: IntervalSet<i32> = [(0, 5), (25, 28), (29, 30)]
+            .into_iter()
+            .map(|(s, e)| Interval::new(s, e))
+            .collect();
+
+        assert_eq!(a.union(&b), union);
+        assert_eq!(a.intersection(&b), intersection);
+        assert_eq!(a.difference(&b), difference);
+        assert_eq!(a.min(), Some(0));
+    }
+
+    #[test]
+    fn can_split_cuboids() {
+        let cuboid = Cuboid([Interval::inclusive(1u64, 10), Interval::inclusive(1, 4)]);
+        assert_eq!(cuboid.volume(), 40);
+
+        let (below, above) = cuboid.split_at(0, 4);
+        assert_eq!(below.volume(), 12);
+        assert_eq!(above.volume(), 28);
+        assert!(below.contains([3, 4]) && !below.contains([4, 4]));
+
+        let (empty, all) = cuboid.split_at(1, 0);
+        assert!(empty.is_empty());
+        assert_eq!(all, cuboid);
+        assert_eq!(below.intersection(&above).volume(), 0);
+    }
+}
@@ -0,0 +1,71 @@
+mod answer;
+pub mod cycle;
+pub mod expr;
+mod geometry;
+pub mod graph;
+mod grid;
+mod interval;
+pub mod math;
+mod ocr;
+mod registry;
+pub mod search;
+mod solution;
+
+pub use answer::Answer;
+pub use geometry::{Compass, Direction, Hex, Point2, Point3};
+pub use grid::{Grid, Pos};
+pub use interval::{Cuboid, Interval, IntervalSet};
+pub use ocr::ocr;
+pub use registry::{Error, Registry};
+pub use solution::{Runner, Solution, SolveError, Timed};
+
+#[macro_export]
+macro_rules! register_days {
+    ($year:literal; $($d:literal => $module:ident::$solution:ident),+ $(,)?) => {
+        pub fn register(registry: &mut $crate::Registry) {
+            $(
+              registry.register($year, $d, $module::$solution);
+            )+
+        }
+    };
+}
+
+/// Generates a test per part that runs a solution on an example from the crate's `fixtures`
+/// directory, `fixtures/dayN.txt` unless another file is given with `in`.
+///
+/// ```ignore
+/// aoc_core::example_tests!(
+///     day1::Day1 { part1 => 24000, part2 => 45000 },
+///     day9::Day9 { part1 => 13, part2 in "day9-2.txt" => 36 },
+/// );
+/// ```
+// the solutions live in the calling crate
+#[allow(clippy::crate_in_macro_def)]
+#[macro_export]
+macro_rules! example_tests {
+    ($($module:ident::$solution:ident { $($part:ident $(in $file:literal)? => $answer:expr),+ $(,)? }),+ $(,)?) => {
+        #[cfg(test)]
+        mod examples {
+            $(
+              mod $module {
+                  $(
+                    #[test]
+                    fn $part() {
+                        let input = $crate::example_tests!(@input $module $($file)?);
+                        let answers = $crate::Runner::run(&crate::$module::$solution, input, &[$crate::example_tests!(@part $part)]);
+                        assert_eq!(answers, vec![Ok($crate::Answer::from($answer))]);
+                    }
+                  )+
+              }
+            )+
+        }
+    };
+    (@input $module:ident) => {
+        include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/fixtures/", stringify!($module), ".txt"))
+    };
+    (@input $module:ident $file:literal) => {
+        include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/fixtures/", $file))
+    };
+    (@part part1) => { 1 };
+    (@part part2) => { 2 };
+}
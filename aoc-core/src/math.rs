@@ -0,0 +1,365 @@
+use std::{
+    cmp::Ordering,
+    fmt::Display,
+    ops::{Add, Div, Mul, Neg, Rem, Sub},
+};
+
+/// Greatest common divisor of two non-negative numbers, `gcd(0, 0)` is 0.
+pub fn gcd<T>(mut a: T, mut b: T) -> T
+where
+    T: Copy + PartialEq + Rem<Output = T> + From<u8>,
+{
+    while b != T::from(0) {
+        (a, b) = (b, a % b);
+    }
+
+    a
+}
+
+/// Least common multiple of two non-negative numbers, 0 if either of them is.
+pub fn lcm<T>(a: T, b: T) -> T
+where
+    T: Copy + PartialEq + Rem<Output = T> + Div<Output = T> + Mul<Output = T> + From<u8>,
+{
+    if a == T::from(0) || b == T::from(0) {
+        return T::from(0);
+    }
+
+    a / gcd(a, b) * b
+}
+
+pub fn gcd_all<T>(values: impl IntoIterator<Item = T>) -> T
+where
+    T: Copy + PartialEq + Rem<Output = T> + From<u8>,
+{
+    values.into_iter().fold(T::from(0), gcd)
+}
+
+pub fn lcm_all<T>(values: impl IntoIterator<Item = T>) -> T
+where
+    T: Copy + PartialEq + Rem<Output = T> + Div<Output = T> + Mul<Output = T> + From<u8>,
+{
+    values.into_iter().fold(T::from(1), lcm)
+}
+
+/// Returns `(g, x, y)` where `g` is the non-negative gcd of `a` and `b` and `a * x + b * y == g`.
+pub fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+
+    while r != 0 {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_x, x) = (x, old_x - q * x);
+        (old_y, y) = (y, old_y - q * y);
+    }
+
+    if old_r < 0 {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+/// The `x` in `0..m` with `a * x ≡ 1 (mod m)`, if `a` and `m` are coprime.
+pub fn mod_inv(a: i128, m: i128) -> Option<i128> {
+    let (g, x, _) = extended_gcd(a.rem_euclid(m), m);
+    (g == 1).then(|| x.rem_euclid(m))
+}
+
+pub fn checked_lcm(a: i128, b: i128) -> Option<i128> {
+    if a == 0 || b == 0 {
+        return Some(0);
+    }
+
+    (a / gcd(a.abs(), b.abs())).checked_mul(b).map(i128::abs)
+}
+
+/// `a * b mod m` in `0..m`, `None` if the product overflows.
+pub fn checked_mul_mod(a: i128, b: i128, m: i128) -> Option<i128> {
+    Some(a.checked_mul(b)?.rem_euclid(m))
+}
+
+/// Solves the system `x ≡ r (mod m)` for every `(r, m)` pair, the moduli don't have to be
+/// coprime. Returns the smallest non-negative `x` along with the lcm of the moduli, every
+/// solution is `x` plus a multiple of it. `None` when the congruences contradict each other or
+/// the result doesn't fit in an i128.
+pub fn crt(congruences: impl IntoIterator<Item = (i128, i128)>) -> Option<(i128, i128)> {
+    congruences
+        .into_iter()
+        .try_fold((0, 1), |(r1, m1), (r2, m2)| {
+            let r2 = r2.rem_euclid(m2);
+            let (g, p, _) = extended_gcd(m1, m2);
+            let diff = r2.checked_sub(r1)?;
+            if diff % g != 0 {
+                return None;
+            }
+
+            // x = r1 + m1 * k, where m1 * k ≡ diff (mod m2)
+            let step = m2 / g;
+            let k = checked_mul_mod((diff / g).rem_euclid(step), p.rem_euclid(step), step)?;
+            let m = (m1 / g).checked_mul(m2)?;
+            let x = r1.checked_add(m1.checked_mul(k)?)?.rem_euclid(m);
+
+            Some((x, m))
+        })
+}
+
+/// The smallest solution of [`crt`] that is at least `min`.
+pub fn crt_at_least(
+    congruences: impl IntoIterator<Item = (i128, i128)>,
+    min: i128,
+) -> Option<i128> {
+    let (x, m) = crt(congruences)?;
+    let behind = min.checked_sub(x)?.max(0);
+
+    let cycles = (behind + m - 1) / m;
+
+    x.checked_add(cycles.checked_mul(m)?)
+}
+
+/// An exact fraction, always stored reduced with a positive denominator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rational {
+    num: i128,
+    den: i128,
+}
+
+impl Rational {
+    pub const ZERO: Self = Rational { num: 0, den: 1 };
+    pub const ONE: Self = Rational { num: 1, den: 1 };
+
+    /// `num / den`, `None` if `den` is 0 or either is `i128::MIN`.
+    pub fn new(num: i128, den: i128) -> Option<Self> {
+        if den == 0 {
+            return None;
+        }
+
+        let g = gcd(num.checked_abs()?, den.checked_abs()?) * den.signum();
+        Some(Rational {
+            num: num / g,
+            den: den / g,
+        })
+    }
+
+    pub fn numer(&self) -> i128 {
+        self.num
+    }
+
+    pub fn denom(&self) -> i128 {
+        self.den
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.num == 0
+    }
+
+    /// The value as a whole number, if it is one.
+    pub fn to_integer(&self) -> Option<i128> {
+        (self.den == 1).then_some(self.num)
+    }
+
+    pub fn recip(&self) -> Option<Self> {
+        Rational::new(self.den, self.num)
+    }
+
+    /// `self + rhs`, `None` if the result doesn't fit.
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        let g = gcd(self.den, rhs.den);
+        let den = (self.den / g).checked_mul(rhs.den)?;
+        let num =
+            (self.num.checked_mul(rhs.den / g)?).checked_add(rhs.num.checked_mul(self.den / g)?)?;
+        Rational::new(num, den)
+    }
+
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        self.checked_add(rhs.checked_neg()?)
+    }
+
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        // cross reduce first to keep the products small
+        let (a, b) = (gcd(self.num.abs(), rhs.den), gcd(rhs.num.abs(), self.den));
+        Rational::new(
+            (self.num / a).checked_mul(rhs.num / b)?,
+            (self.den / b).checked_mul(rhs.den / a)?,
+        )
+    }
+
+    /// `self / rhs`, `None` if `rhs` is 0 or the result doesn't fit.
+    pub fn checked_div(self, rhs: Self) -> Option<Self> {
+        self.checked_mul(rhs.recip()?)
+    }
+
+    pub fn checked_neg(self) -> Option<Self> {
+        Some(Rational {
+            num: self.num.checked_neg()?,
+            den: self.den,
+        })
+    }
+}
+
+/// Compares `an / ad` with `bn / bd` through their continued fractions, so nothing is multiplied.
+/// Both denominators have to be positive.
+fn cmp_fractions(an: i128, ad: i128, bn: i128, bd: i128) -> Ordering {
+    let (ar, br) = (an.rem_euclid(ad), bn.rem_euclid(bd));
+
+    an.div_euclid(ad)
+        .cmp(&bn.div_euclid(bd))
+        .then_with(|| match (ar, br) {
+            (0, 0) => Ordering::Equal,
+            (0, _) => Ordering::Less,
+            (_, 0) => Ordering::Greater,
+            // the smaller remainder has the larger reciprocal
+            _ => cmp_fractions(bd, br, ad, ar),
+        })
+}
+
+impl From<i64> for Rational {
+    fn from(num: i64) -> Self {
+        Rational {
+            num: num as i128,
+            den: 1,
+        }
+    }
+}
+
+impl PartialOrd for Rational {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Rational {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (
+            self.num.checked_mul(other.den),
+            other.num.checked_mul(self.den),
+        ) {
+            (Some(lhs), Some(rhs)) => lhs.cmp(&rhs),
+            _ => cmp_fractions(self.num, self.den, other.num, other.den),
+        }
+    }
+}
+
+// the operators panic on overflow like the integers do, use the checked versions to handle it
+
+impl Add for Rational {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        self.checked_add(rhs).expect("attempt to add with overflow")
+    }
+}
+
+impl Sub for Rational {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.checked_sub(rhs)
+            .expect("attempt to subtract with overflow")
+    }
+}
+
+impl Mul for Rational {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.checked_mul(rhs)
+            .expect("attempt to multiply with overflow")
+    }
+}
+
+impl Div for Rational {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        assert!(!rhs.is_zero(), "attempt to divide by zero");
+        self.checked_div(rhs)
+            .expect("attempt to divide with overflow")
+    }
+}
+
+impl Neg for Rational {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        self.checked_neg().expect("attempt to negate with overflow")
+    }
+}
+
+impl Display for Rational {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.den {
+            1 => write!(f, "{}", self.num),
+            den => write!(f, "{}/{den}", self.num),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_find_gcd_and_lcm() {
+        assert_eq!(gcd(12u64, 18), 6);
+        assert_eq!(gcd(0usize, 7), 7);
+        assert_eq!(lcm(4usize, 6), 12);
+        assert_eq!(lcm(0u32, 6), 0);
+        assert_eq!(gcd_all([12u64, 18, 27]), 3);
+        assert_eq!(lcm_all([2u64, 3, 4, 5]), 60);
+        assert_eq!(lcm_all(Vec::<u64>::new()), 1);
+    }
+
+    #[test]
+    fn can_invert() {
+        let (g, x, y) = extended_gcd(240, 46);
+        assert_eq!(g, 2);
+        assert_eq!(240 * x + 46 * y, 2);
+
+        assert_eq!(mod_inv(3, 11), Some(4));
+        assert_eq!(mod_inv(-3, 11), Some(7));
+        assert_eq!(mod_inv(4, 10), None);
+    }
+
+    #[test]
+    fn can_solve_congruences() {
+        assert_eq!(crt([(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        assert_eq!(crt([(2, 4), (4, 6)]), Some((10, 12)));
+        assert_eq!(crt([(1, 4), (2, 6)]), None);
+        assert_eq!(crt([(-1, 5), (3, 4)]), Some((19, 20)));
+        assert_eq!(crt(Vec::new()), Some((0, 1)));
+        assert_eq!(crt_at_least([(2, 4), (4, 6)], 11), Some(22));
+        assert_eq!(crt_at_least([(2, 4), (4, 6)], 10), Some(10));
+
+        let big = (1i128 << 61) - 1;
+        assert_eq!(crt([(1, big), (2, big - 2), (3, big - 6)]), None);
+        assert_eq!(checked_lcm(big, big - 2), Some(big * (big - 2)));
+        assert_eq!(checked_lcm(big * (big - 2), big - 6), None);
+    }
+
+    #[test]
+    fn can_do_exact_fractions() {
+        let third = Rational::new(2, -6).unwrap();
+        assert_eq!((third.numer(), third.denom()), (-1, 3));
+        assert_eq!(third + Rational::ONE, Rational::new(2, 3).unwrap());
+        assert_eq!((third * Rational::from(-3)).to_integer(), Some(1));
+        assert_eq!(Rational::ONE / third, Rational::from(-3));
+        assert!(third < Rational::ZERO);
+        assert_eq!(Rational::new(1, 0), None);
+
+        let huge = Rational::new(i128::MAX, 3).unwrap();
+        assert_eq!(huge.checked_add(huge), None);
+        assert_eq!(huge.checked_mul(Rational::from(2)), None);
+        assert_eq!(huge.checked_div(Rational::ZERO), None);
+        assert_eq!(huge.checked_sub(huge), Some(Rational::ZERO));
+        // 1 - 1/MAX against 1 - 1/(MAX - 1), the cross products don't fit
+        assert!(
+            Rational::new(i128::MAX - 1, i128::MAX).unwrap()
+                > Rational::new(i128::MAX - 2, i128::MAX - 1).unwrap()
+        );
+        assert!(huge > Rational::new(i128::MAX, 5).unwrap() && -huge < Rational::ZERO);
+        assert_eq!(third.to_string(), "-1/3");
+    }
+}
@@ -0,0 +1,197 @@
+//! Reads the block letters some puzzles draw on a screen, in the 4x6 font and the larger 6x10 one.
+
+const FONT_6: [(char, &str); 18] = [
+    ('A', ".##.\n#..#\n#..#\n####\n#..#\n#..#"),
+    ('B', "###.\n#..#\n###.\n#..#\n#..#\n###."),
+    ('C', ".##.\n#..#\n#...\n#...\n#..#\n.##."),
+    ('E', "####\n#...\n###.\n#...\n#...\n####"),
+    ('F', "####\n#...\n###.\n#...\n#...\n#..."),
+    ('G', ".##.\n#..#\n#...\n#.##\n#..#\n.###"),
+    ('H', "#..#\n#..#\n####\n#..#\n#..#\n#..#"),
+    ('I', ".###\n..#.\n..#.\n..#.\n..#.\n.###"),
+    ('J', "..##\n...#\n...#\n...#\n#..#\n.##."),
+    ('K', "#..#\n#.#.\n##..\n#.#.\n#.#.\n#..#"),
+    ('L', "#...\n#...\n#...\n#...\n#...\n####"),
+    ('O', ".##.\n#..#\n#..#\n#..#\n#..#\n.##."),
+    ('P', "###.\n#..#\n#..#\n###.\n#...\n#..."),
+    ('R', "###.\n#..#\n#..#\n###.\n#.#.\n#..#"),
+    ('S', ".###\n#...\n#...\n.##.\n...#\n###."),
+    ('U', "#..#\n#..#\n#..#\n#..#\n#..#\n.##."),
+    ('Y', "#...#\n#...#\n.#.#.\n..#..\n..#..\n..#.."),
+    ('Z', "####\n...#\n..#.\n.#..\n#...\n####"),
+];
+
+const FONT_10: [(char, &str); 15] = [
+    (
+        'A',
+        "..##..\n.#..#.\n#....#\n#....#\n#....#\n######\n#....#\n#....#\n#....#\n#....#",
+    ),
+    (
+        'B',
+        "#####.\n#....#\n#....#\n#....#\n#####.\n#....#\n#....#\n#....#\n#....#\n#####.",
+    ),
+    (
+        'C',
+        ".####.\n#....#\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#....#\n.####.",
+    ),
+    (
+        'E',
+        "######\n#.....\n#.....\n#.....\n#####.\n#.....\n#.....\n#.....\n#.....\n######",
+    ),
+    (
+        'F',
+        "######\n#.....\n#.....\n#.....\n#####.\n#.....\n#.....\n#.....\n#.....\n#.....",
+    ),
+    (
+        'G',
+        ".####.\n#....#\n#.....\n#.....\n#.....\n#..###\n#....#\n#....#\n#...##\n.###.#",
+    ),
+    (
+        'H',
+        "#....#\n#....#\n#....#\n#....#\n######\n#....#\n#....#\n#....#\n#....#\n#....#",
+    ),
+    (
+        'J',
+        "...###\n....#.\n....#.\n....#.\n....#.\n....#.\n....#.\n#...#.\n#...#.\n.###..",
+    ),
+    (
+        'K',
+        "#....#\n#...#.\n#..#..\n#.#...\n##....\n##....\n#.#...\n#..#..\n#...#.\n#....#",
+    ),
+    (
+        'L',
+        "#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n######",
+    ),
+    (
+        'N',
+        "#....#\n##...#\n##...#\n#.#..#\n#.#..#\n#..#.#\n#..#.#\n#...##\n#...##\n#....#",
+    ),
+    (
+        'P',
+        "#####.\n#....#\n#....#\n#....#\n#####.\n#.....\n#.....\n#.....\n#.....\n#.....",
+    ),
+    (
+        'R',
+        "#####.\n#....#\n#....#\n#....#\n#####.\n#..#..\n#...#.\n#...#.\n#....#\n#....#",
+    ),
+    (
+        'X',
+        "#....#\n#....#\n.#..#.\n.#..#.\n..##..\n..##..\n.#..#.\n.#..#.\n#....#\n#....#",
+    ),
+    (
+        'Z',
+        "######\n.....#\n.....#\n....#.\n...#..\n..#...\n.#....\n#.....\n#.....\n######",
+    ),
+];
+
+fn is_lit(c: char) -> bool {
+    matches!(c, '#' | '█')
+}
+
+/// Splits a screen on its blank columns, each glyph is a list of lit columns as bitmasks.
+fn glyphs<S: AsRef<str>>(rows: &[S]) -> Vec<Vec<u16>> {
+    let rows: Vec<Vec<bool>> = rows
+        .iter()
+        .map(|row| row.as_ref().chars().map(is_lit).collect())
+        .collect();
+    let width = rows.iter().map(|row| row.len()).max().unwrap_or(0);
+
+    let columns = (0..width).map(|col| {
+        rows.iter().enumerate().fold(0, |mask, (r, row)| {
+            mask | (u16::from(row.get(col).copied().unwrap_or(false)) << r)
+        })
+    });
+
+    let mut glyphs = Vec::new();
+    let mut glyph = Vec::new();
+    for column in columns {
+        if column != 0 {
+            glyph.push(column);
+        } else if !glyph.is_empty() {
+            glyphs.push(std::mem::take(&mut glyph));
+        }
+    }
+    if !glyph.is_empty() {
+        glyphs.push(glyph);
+    }
+
+    glyphs
+}
+
+fn decode(font: &[(char, &str)], glyph: &[u16]) -> Option<char> {
+    font.iter()
+        .find(|(_, drawing)| {
+            let rows: Vec<&str> = drawing.lines().collect();
+            glyphs(&rows).first().is_some_and(|known| known == glyph)
+        })
+        .map(|(letter, _)| *letter)
+}
+
+/// Reads the letters on a screen of `#` (lit) and `.` (dark) rows, or `None` if the screen
+/// isn't 6 or 10 rows high or has a shape that isn't a known letter.
+pub fn ocr<S: AsRef<str>>(rows: &[S]) -> Option<String> {
+    let font: &[(char, &str)] = match rows.len() {
+        6 => &FONT_6,
+        10 => &FONT_10,
+        _ => return None,
+    };
+
+    let glyphs = glyphs(rows);
+    if glyphs.is_empty() {
+        return None;
+    }
+
+    glyphs.iter().map(|glyph| decode(font, glyph)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn draw(font: &[(char, &str)], text: &str, gap: usize) -> Vec<String> {
+        let letters: Vec<Vec<&str>> = text
+            .chars()
+            .map(|c| font.iter().find(|(letter, _)| *letter == c).unwrap().1)
+            .map(|drawing| drawing.lines().collect())
+            .collect();
+
+        (0..letters[0].len())
+            .map(|r| {
+                letters
+                    .iter()
+                    .map(|letter| letter[r])
+                    .collect::<Vec<&str>>()
+                    .join(&".".repeat(gap))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn can_read_small_letters() {
+        let screen = [
+            "###..####.####.#..#.####.####.#..#..##..",
+            "#..#....#.#....#.#..#....#....#..#.#..#.",
+            "#..#...#..###..##...###..###..####.#..#.",
+            "###...#...#....#.#..#....#....#..#.####.",
+            "#.#..#....#....#.#..#....#....#..#.#..#.",
+            "#..#.####.####.#..#.####.#....#..#.#..#.",
+        ];
+        assert_eq!(ocr(&screen), Some(String::from("RZEKEFHA")));
+
+        let alphabet: String = FONT_6.iter().map(|(letter, _)| letter).collect();
+        assert_eq!(ocr(&draw(&FONT_6, &alphabet, 1)), Some(alphabet));
+    }
+
+    #[test]
+    fn can_read_large_letters() {
+        let alphabet: String = FONT_10.iter().map(|(letter, _)| letter).collect();
+        assert_eq!(ocr(&draw(&FONT_10, &alphabet, 2)), Some(alphabet));
+    }
+
+    #[test]
+    fn rejects_unknown_shapes() {
+        assert_eq!(ocr(&["#.#", ".#.", "#.#", ".#.", "#.#", ".#."]), None);
+        assert_eq!(ocr(&["....", "....", "....", "....", "....", "...."]), None);
+        assert_eq!(ocr(&["##", "##"]), None);
+    }
+}
@@ -0,0 +1,200 @@
+use std::{collections::BTreeMap, fmt::Display};
+
+use crate::{Answer, Runner, Solution, SolveError, Timed};
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Error {
+    UnknownDay(u16, u8),
+    InvalidPart(u16, u8, u8),
+    /// The input didn't parse.
+    Parse(u16, u8, SolveError),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnknownDay(year, day) => write!(f, "no solution registered for {year} day {day}"),
+            Self::InvalidPart(year, day, part) => {
+                write!(f, "{year} day {day} has no part {part}")
+            }
+            Self::Parse(year, day, err) => write!(f, "{year} day {day} input: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+#[derive(Default)]
+pub struct Registry {
+    days: BTreeMap<(u16, u8), Box<dyn Runner>>,
+}
+
+impl Registry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn register<S: Solution + 'static>(&mut self, year: u16, day: u8, solution: S) {
+        self.days.insert((year, day), Box::new(solution));
+    }
+
+    pub fn get(&self, year: u16, day: u8) -> Result<&dyn Runner, Error> {
+        self.days
+            .get(&(year, day))
+            .map(|runner| runner.as_ref())
+            .ok_or(Error::UnknownDay(year, day))
+    }
+
+    pub fn years(&self) -> Vec<u16> {
+        let mut years: Vec<u16> = self.days.keys().map(|(year, _)| *year).collect();
+        years.dedup();
+        years
+    }
+
+    pub fn days(&self, year: u16) -> Vec<u8> {
+        self.days
+            .keys()
+            .filter(|(y, _)| *y == year)
+            .map(|(_, day)| *day)
+            .collect()
+    }
+
+    fn get_parts(&self, year: u16, day: u8, parts: &[u8]) -> Result<&dyn Runner, Error> {
+        let runner = self.get(year, day)?;
+
+        match parts
+            .iter()
+            .find(|part| **part < 1 || **part > runner.parts())
+        {
+            Some(part) => Err(Error::InvalidPart(year, day, *part)),
+            None => Ok(runner),
+        }
+    }
+
+    /// Answers every part in `parts` from one parse of the input.
+    pub fn run(
+        &self,
+        year: u16,
+        day: u8,
+        parts: &[u8],
+        input: &str,
+    ) -> Result<Vec<Result<Answer, SolveError>>, Error> {
+        Ok(self.get_parts(year, day, parts)?.run(input, parts))
+    }
+
+    pub fn timed(&self, year: u16, day: u8, parts: &[u8], input: &str) -> Result<Timed, Error> {
+        self.get_parts(year, day, parts)?
+            .timed(input, parts)
+            .map_err(|err| Error::Parse(year, day, err))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        type Input = Vec<i64>;
+
+        fn parse(input: &str) -> Result<Self::Input, SolveError> {
+            Ok(input
+                .lines()
+                .map(|line| line.parse())
+                .collect::<Result<_, _>>()?)
+        }
+
+        fn part1(nums: &Self::Input) -> Result<Answer, SolveError> {
+            Ok(nums.iter().sum::<i64>().into())
+        }
+
+        fn part2(nums: &Self::Input) -> Result<Answer, SolveError> {
+            Ok(nums.iter().product::<i64>().into())
+        }
+    }
+
+    struct Single;
+
+    impl Solution for Single {
+        type Input = ();
+
+        const PARTS: u8 = 1;
+
+        fn parse(_input: &str) -> Result<Self::Input, SolveError> {
+            Ok(())
+        }
+
+        fn part1(_input: &Self::Input) -> Result<Answer, SolveError> {
+            Ok("done".into())
+        }
+    }
+
+    fn registry() -> Registry {
+        let mut registry = Registry::new();
+        registry.register(2022, 1, Sum);
+        registry.register(2022, 25, Single);
+        registry.register(2023, 1, Sum);
+        registry
+    }
+
+    #[test]
+    fn can_run_parts() {
+        let registry = registry();
+        assert_eq!(
+            registry.run(2022, 1, &[1, 2], "2\n3\n4"),
+            Ok(vec![Ok(Answer::Int(9)), Ok(Answer::Int(24))])
+        );
+        assert_eq!(
+            registry.run(2022, 25, &[1], ""),
+            Ok(vec![Ok(Answer::from("done"))])
+        );
+
+        let timed = registry.timed(2023, 1, &[1], "5\n6").unwrap();
+        assert_eq!(timed.parts[0].0, Ok(Answer::Int(11)));
+    }
+
+    #[test]
+    fn rejects_unknown_days_and_parts() {
+        let registry = registry();
+        assert_eq!(
+            registry.run(2022, 2, &[1], ""),
+            Err(Error::UnknownDay(2022, 2))
+        );
+        assert_eq!(
+            registry.run(2022, 1, &[1, 3], ""),
+            Err(Error::InvalidPart(2022, 1, 3))
+        );
+        assert_eq!(
+            registry.run(2022, 25, &[2], ""),
+            Err(Error::InvalidPart(2022, 25, 2))
+        );
+        assert_eq!(
+            Runner::run(&Single, "", &[2]),
+            vec![Err(SolveError::NoPart(2))]
+        );
+    }
+
+    #[test]
+    fn reports_parse_errors() {
+        let registry = registry();
+        let err = SolveError::Failed("invalid digit found in string".to_owned());
+
+        assert_eq!(
+            registry.run(2022, 1, &[1, 2], "2\nx"),
+            Ok(vec![Err(err.clone()), Err(err.clone())])
+        );
+        assert_eq!(
+            registry.timed(2022, 1, &[1], "2\nx").err(),
+            Some(Error::Parse(2022, 1, err))
+        );
+    }
+
+    #[test]
+    fn lists_years_and_days() {
+        let registry = registry();
+        assert_eq!(registry.years(), vec![2022, 2023]);
+        assert_eq!(registry.days(2022), vec![1, 25]);
+        assert!(registry.days(2024).is_empty());
+    }
+}
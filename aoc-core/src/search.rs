@@ -0,0 +1,245 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, VecDeque},
+    hash::Hash,
+};
+
+/// What a search found: the cost to every state it reached, how it got there, and the goal it
+/// stopped at, if any.
+#[derive(Debug, Clone)]
+pub struct Paths<S> {
+    distances: HashMap<S, usize>,
+    previous: HashMap<S, S>,
+    visited: usize,
+    goal: Option<S>,
+}
+
+impl<S: Clone + Eq + Hash> Paths<S> {
+    fn new() -> Self {
+        Self {
+            distances: HashMap::new(),
+            previous: HashMap::new(),
+            visited: 0,
+            goal: None,
+        }
+    }
+
+    pub fn distance(&self, state: &S) -> Option<usize> {
+        self.distances.get(state).copied()
+    }
+
+    pub fn distances(&self) -> &HashMap<S, usize> {
+        &self.distances
+    }
+
+    pub fn into_distances(self) -> HashMap<S, usize> {
+        self.distances
+    }
+
+    /// The states from a start to `state`, both included.
+    pub fn path(&self, state: &S) -> Option<Vec<S>> {
+        if !self.distances.contains_key(state) {
+            return None;
+        }
+
+        let mut path = vec![state.clone()];
+        while let Some(previous) = self.previous.get(path.last().unwrap()) {
+            path.push(previous.clone());
+        }
+        path.reverse();
+
+        Some(path)
+    }
+
+    /// How many states were taken off the queue and expanded.
+    pub fn visited(&self) -> usize {
+        self.visited
+    }
+
+    pub fn goal(&self) -> Option<&S> {
+        self.goal.as_ref()
+    }
+
+    /// The cost of the goal the search stopped at.
+    pub fn goal_distance(&self) -> Option<usize> {
+        self.goal.as_ref().and_then(|goal| self.distance(goal))
+    }
+}
+
+/// Breadth first search where every step costs 1. Stops at the first state `goal` accepts,
+/// pass `|_| false` to explore everything reachable.
+pub fn bfs<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut neighbors: impl FnMut(&S) -> I,
+    mut goal: impl FnMut(&S) -> bool,
+) -> Paths<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut paths = Paths::new();
+    let mut queue = VecDeque::new();
+
+    for start in starts {
+        if paths.distances.insert(start.clone(), 0).is_none() {
+            queue.push_back((start, 0));
+        }
+    }
+
+    while let Some((state, distance)) = queue.pop_front() {
+        paths.visited += 1;
+        if goal(&state) {
+            paths.goal = Some(state);
+            return paths;
+        }
+
+        for next in neighbors(&state) {
+            if paths.distances.contains_key(&next) {
+                continue;
+            }
+
+            paths.distances.insert(next.clone(), distance + 1);
+            paths.previous.insert(next.clone(), state.clone());
+            queue.push_back((next, distance + 1));
+        }
+    }
+
+    paths
+}
+
+/// Cheapest path search, `neighbors` yields the next states with the cost of getting there.
+pub fn dijkstra<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    neighbors: impl FnMut(&S) -> I,
+    goal: impl FnMut(&S) -> bool,
+) -> Paths<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, usize)>,
+{
+    a_star(starts, neighbors, |_| 0, goal)
+}
+
+/// Cheapest path search guided by `heuristic`, which must never overestimate the remaining cost.
+pub fn a_star<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut neighbors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> usize,
+    mut goal: impl FnMut(&S) -> bool,
+) -> Paths<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, usize)>,
+{
+    let mut paths = Paths::new();
+    // the heap holds indices into `states`, so states don't have to be Ord
+    let mut states = Vec::new();
+    let mut open = BinaryHeap::new();
+
+    for start in starts {
+        if paths.distances.insert(start.clone(), 0).is_none() {
+            open.push(Reverse((heuristic(&start), 0, states.len())));
+            states.push(start);
+        }
+    }
+
+    while let Some(Reverse((_, distance, id))) = open.pop() {
+        let state = states[id].clone();
+        if paths.distances[&state] < distance {
+            continue;
+        }
+
+        paths.visited += 1;
+        if goal(&state) {
+            paths.goal = Some(state);
+            return paths;
+        }
+
+        for (next, cost) in neighbors(&state) {
+            let next_distance = distance + cost;
+            if paths
+                .distances
+                .get(&next)
+                .is_some_and(|known| *known <= next_distance)
+            {
+                continue;
+            }
+
+            paths.distances.insert(next.clone(), next_distance);
+            paths.previous.insert(next.clone(), state.clone());
+            open.push(Reverse((
+                next_distance + heuristic(&next),
+                next_distance,
+                states.len(),
+            )));
+            states.push(next);
+        }
+    }
+
+    paths
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Grid;
+
+    fn maze() -> Grid<char> {
+        Grid::parse("S.#.\n.##.\n...E", |c| c)
+    }
+
+    #[test]
+    fn can_search_breadth_first() {
+        let maze = maze();
+        let paths = bfs(
+            [(0, 0)],
+            |pos| {
+                maze.neighbors4(*pos)
+                    .filter(|next| maze[*next] != '#')
+                    .collect::<Vec<_>>()
+            },
+            |pos| maze[*pos] == 'E',
+        );
+
+        assert_eq!(paths.goal(), Some(&(2, 3)));
+        assert_eq!(paths.goal_distance(), Some(5));
+        assert_eq!(
+            paths.path(&(2, 3)),
+            Some(vec![(0, 0), (1, 0), (2, 0), (2, 1), (2, 2), (2, 3)])
+        );
+        assert_eq!(paths.path(&(0, 3)), None);
+
+        let everything = bfs(
+            [(0, 0)],
+            |pos| maze.neighbors4(*pos).collect::<Vec<_>>(),
+            |_| false,
+        );
+        assert_eq!(everything.visited(), 12);
+        assert_eq!(everything.distance(&(0, 3)), Some(3));
+    }
+
+    #[test]
+    fn can_find_cheapest_paths() {
+        let costs = Grid::parse("1163\n1381\n2136", |c| c.to_digit(10).unwrap() as usize);
+        let target = (2, 3);
+        let neighbors = |pos: &(usize, usize)| {
+            costs
+                .neighbors4(*pos)
+                .map(|next| (next, costs[next]))
+                .collect::<Vec<_>>()
+        };
+
+        let plain = dijkstra([(0, 0)], neighbors, |pos| *pos == target);
+        let guided = a_star(
+            [(0, 0)],
+            neighbors,
+            |pos| target.0 - pos.0 + target.1 - pos.1,
+            |pos| *pos == target,
+        );
+
+        assert_eq!(plain.goal_distance(), Some(13));
+        assert_eq!(guided.goal_distance(), Some(13));
+        assert!(guided.visited() <= plain.visited());
+        assert_eq!(guided.path(&target).unwrap().first(), Some(&(0, 0)));
+    }
+}
@@ -0,0 +1,98 @@
+use std::{
+    fmt::Display,
+    time::{Duration, Instant},
+};
+
+use crate::Answer;
+
+/// Why a day couldn't produce an answer. Any error type converts into one with `?`, keeping its
+/// message.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SolveError {
+    /// The day has fewer parts than that.
+    NoPart(u8),
+    Failed(String),
+}
+
+impl Display for SolveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NoPart(part) => write!(f, "this day has no part {part}"),
+            Self::Failed(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl<E: std::error::Error> From<E> for SolveError {
+    fn from(err: E) -> Self {
+        Self::Failed(err.to_string())
+    }
+}
+
+pub trait Solution {
+    type Input;
+
+    /// Day 25 only has one part.
+    const PARTS: u8 = 2;
+
+    fn parse(input: &str) -> Result<Self::Input, SolveError>;
+
+    fn part1(input: &Self::Input) -> Result<Answer, SolveError>;
+
+    fn part2(_input: &Self::Input) -> Result<Answer, SolveError> {
+        Err(SolveError::NoPart(2))
+    }
+}
+
+/// Object safe view of a [`Solution`], so days of different types fit in one registry.
+pub trait Runner {
+    fn parts(&self) -> u8;
+
+    /// Answers every part in `parts`, parsing the input once. A parse error is every part's
+    /// answer.
+    fn run(&self, input: &str, parts: &[u8]) -> Vec<Result<Answer, SolveError>>;
+
+    fn timed(&self, input: &str, parts: &[u8]) -> Result<Timed, SolveError>;
+}
+
+/// How long parsing took, and every part's answer along with how long solving it took.
+#[derive(Debug)]
+pub struct Timed {
+    pub parse: Duration,
+    pub parts: Vec<(Result<Answer, SolveError>, Duration)>,
+}
+
+impl<S: Solution> Runner for S {
+    fn parts(&self) -> u8 {
+        S::PARTS
+    }
+
+    fn run(&self, input: &str, parts: &[u8]) -> Vec<Result<Answer, SolveError>> {
+        match self.timed(input, parts) {
+            Ok(timed) => timed.parts.into_iter().map(|(answer, _)| answer).collect(),
+            Err(err) => vec![Err(err); parts.len()],
+        }
+    }
+
+    fn timed(&self, input: &str, parts: &[u8]) -> Result<Timed, SolveError> {
+        let start = Instant::now();
+        let input = S::parse(input)?;
+        let parse = start.elapsed();
+
+        let parts = parts
+            .iter()
+            .map(|part| {
+                let start = Instant::now();
+                let answer = match part {
+                    1 => S::part1(&input),
+                    2 if S::PARTS >= 2 => S::part2(&input),
+                    part => Err(SolveError::NoPart(*part)),
+                };
+
+                (answer, start.elapsed())
+            })
+            .collect();
+
+        Ok(Timed { parse, parts })
+    }
+}
@@ -0,0 +1,82 @@
+use std::{collections::BTreeMap, fs, io, path::Path};
+
+use aoc_core::Answer;
+use serde::{Deserialize, Serialize};
+
+use crate::error::Error;
+
+type Parts = BTreeMap<u8, Answer>;
+type Inputs = BTreeMap<String, Parts>;
+
+/// Confirmed answers keyed by year, day, input name and part.
+#[derive(Default, Serialize, Deserialize)]
+pub struct AnswerDb(BTreeMap<u16, BTreeMap<u8, Inputs>>);
+
+impl AnswerDb {
+    pub fn load(path: &Path) -> Result<Self, Error> {
+        match fs::read_to_string(path) {
+            Ok(json) => serde_json::from_str(&json)
+                .map_err(|err| Error::Answers(path.to_owned(), err.to_string())),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(Error::Answers(path.to_owned(), err.to_string())),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), Error> {
+        let json = serde_json::to_string_pretty(self).unwrap();
+        fs::write(path, json + "\n").map_err(|err| Error::Answers(path.to_owned(), err.to_string()))
+    }
+
+    pub fn get(&self, year: u16, day: u8, input: &str, part: u8) -> Option<&Answer> {
+        self.0.get(&year)?.get(&day)?.get(input)?.get(&part)
+    }
+
+    pub fn set(&mut self, year: u16, day: u8, input: &str, part: u8, answer: Answer) {
+        self.0
+            .entry(year)
+            .or_default()
+            .entry(day)
+            .or_default()
+            .entry(input.to_owned())
+            .or_default()
+            .insert(part, answer);
+    }
+
+    pub fn inputs(&self, year: u16, day: u8) -> Vec<String> {
+        self.0
+            .get(&year)
+            .and_then(|days| days.get(&day))
+            .map(|inputs| inputs.keys().cloned().collect())
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_store_and_reload_answers() {
+        let path = std::env::temp_dir().join(format!("aoc-answers-{}.json", std::process::id()));
+
+        let mut db = AnswerDb::load(&path).unwrap();
+        assert_eq!(db.get(2022, 1, "default", 1), None);
+
+        db.set(2022, 1, "default", 1, Answer::Int(24000));
+        db.set(2022, 1, "bob", 2, Answer::from("CMZ"));
+        db.set(2022, 10, "default", 2, Answer::grid(["#..", ".#."]));
+        db.save(&path).unwrap();
+
+        let db = AnswerDb::load(&path).unwrap();
+        assert_eq!(db.get(2022, 1, "default", 1), Some(&Answer::Int(24000)));
+        assert_eq!(db.get(2022, 1, "bob", 2), Some(&Answer::from("CMZ")));
+        assert_eq!(
+            db.get(2022, 10, "default", 2),
+            Some(&Answer::grid(["#..", ".#."]))
+        );
+        assert_eq!(db.get(2022, 1, "bob", 1), None);
+        assert_eq!(db.inputs(2022, 1), vec!["bob", "default"]);
+
+        fs::remove_file(&path).unwrap();
+    }
+}
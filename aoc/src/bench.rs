@@ -0,0 +1,231 @@
+use std::{fs, path::Path, time::Duration};
+
+use aoc_core::{Answer, Registry};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    cli::BenchArgs,
+    error::{self, Error},
+    input::{InputCache, DEFAULT_INPUT},
+    target,
+};
+
+/// Timings in nanoseconds.
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+struct Stats {
+    min: u64,
+    median: u64,
+    max: u64,
+}
+
+impl Stats {
+    fn new(mut samples: Vec<Duration>) -> Self {
+        samples.sort();
+        let nanos = |duration: Duration| duration.as_nanos() as u64;
+
+        Self {
+            min: nanos(samples[0]),
+            median: nanos(samples[samples.len() / 2]),
+            max: nanos(samples[samples.len() - 1]),
+        }
+    }
+}
+
+impl std::fmt::Display for Stats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let [min, median, max] = [self.min, self.median, self.max].map(Duration::from_nanos);
+        write!(f, "{min:>10.3?} {median:>10.3?} {max:>10.3?}")
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct Record {
+    year: u16,
+    day: u8,
+    part: u8,
+    input: String,
+    runs: u32,
+    answer: Answer,
+    parse: Stats,
+    solve: Stats,
+}
+
+impl Record {
+    fn is_same(&self, other: &Record) -> bool {
+        (self.year, self.day, self.part, &self.input)
+            == (other.year, other.day, other.part, &other.input)
+    }
+
+    fn median(&self) -> u64 {
+        self.parse.median + self.solve.median
+    }
+
+    /// Change of the median total time relative to `baseline`, in percent.
+    fn change(&self, baseline: &Record) -> f64 {
+        (self.median() as f64 / baseline.median().max(1) as f64 - 1.0) * 100.0
+    }
+}
+
+fn load(path: &Path) -> Result<Vec<Record>, Error> {
+    let json =
+        fs::read_to_string(path).map_err(|err| Error::Bench(path.to_owned(), err.to_string()))?;
+    serde_json::from_str(&json).map_err(|err| Error::Bench(path.to_owned(), err.to_string()))
+}
+
+fn save(path: &Path, records: &[Record]) -> Result<(), Error> {
+    let json = serde_json::to_string_pretty(records).unwrap();
+    fs::write(path, json + "\n").map_err(|err| Error::Bench(path.to_owned(), err.to_string()))
+}
+
+pub fn bench(registry: &Registry, cache: &InputCache, args: &BenchArgs) -> Result<(), Error> {
+    let input_name = args.selection.input.as_deref().unwrap_or(DEFAULT_INPUT);
+    let baseline = args.baseline.as_deref().map(load).transpose()?;
+    let mut records = Vec::new();
+    let mut failed = 0;
+    let mut regressed = 0;
+
+    println!(
+        "{:<20}  {:>10} {:>10} {:>10}  {:>10} {:>10} {:>10}",
+        "", "parse min", "median", "max", "solve min", "median", "max"
+    );
+
+    for target in target::targets(registry, &args.selection)? {
+        let input = match cache.get(target.year, target.day, input_name) {
+            Ok(input) => input,
+            Err(err) => {
+                eprintln!("error: {err}");
+                failed += 1;
+                continue;
+            }
+        };
+
+        let mut parse = Vec::new();
+        let mut solve = vec![Vec::new(); target.parts.len()];
+        let mut answers = Vec::new();
+
+        for _ in 0..args.runs {
+            let timed = error::catch_panic(target.year, target.day, || {
+                Ok(registry.timed(target.year, target.day, &target.parts, &input)?)
+            });
+            let timed = match timed {
+                Ok(timed) => timed,
+                Err(err) => {
+                    eprintln!("error: {err}");
+                    failed += 1;
+                    break;
+                }
+            };
+
+            parse.push(timed.parse);
+            answers.clear();
+            for (samples, (answer, duration)) in solve.iter_mut().zip(timed.parts) {
+                samples.push(duration);
+                answers.push(answer);
+            }
+        }
+        if parse.len() < args.runs as usize {
+            continue;
+        }
+
+        for ((part, solve), answer) in target.parts.iter().zip(solve).zip(answers) {
+            let answer = match answer {
+                Ok(answer) => answer,
+                Err(err) => {
+                    eprintln!(
+                        "error: {} day {} part {part}: {err}",
+                        target.year, target.day
+                    );
+                    failed += 1;
+                    continue;
+                }
+            };
+
+            let record = Record {
+                year: target.year,
+                day: target.day,
+                part: *part,
+                input: input_name.to_owned(),
+                runs: args.runs,
+                answer,
+                parse: Stats::new(parse.clone()),
+                solve: Stats::new(solve),
+            };
+
+            let name = format!("{} day {:>2} part {part}", record.year, record.day);
+            print!("{name:<20}  {}  {}", record.parse, record.solve);
+
+            let previous = baseline
+                .iter()
+                .flatten()
+                .find(|previous| previous.is_same(&record));
+            if let Some(previous) = previous {
+                let change = record.change(previous);
+                print!("  {change:+.1}%");
+                if change > args.threshold {
+                    print!(" REGRESSED");
+                    regressed += 1;
+                }
+                if record.answer != previous.answer {
+                    print!(" answer changed");
+                }
+            }
+            println!();
+
+            records.push(record);
+        }
+    }
+
+    if let Some(path) = &args.json {
+        save(path, &records)?;
+    }
+
+    match (failed, regressed) {
+        (0, 0) => Ok(()),
+        (0, regressed) => Err(Error::Regressed(regressed)),
+        (failed, _) => Err(Error::Failed(failed)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(parse: u64, solve: u64) -> Record {
+        let stats = |median| Stats {
+            min: median,
+            median,
+            max: median,
+        };
+
+        Record {
+            year: 2022,
+            day: 1,
+            part: 1,
+            input: String::from(DEFAULT_INPUT),
+            runs: 1,
+            answer: Answer::Int(0),
+            parse: stats(parse),
+            solve: stats(solve),
+        }
+    }
+
+    #[test]
+    fn can_summarize_samples() {
+        let samples = [5, 1, 4, 2, 3].map(Duration::from_nanos).to_vec();
+        assert_eq!(
+            Stats::new(samples),
+            Stats {
+                min: 1,
+                median: 3,
+                max: 5
+            }
+        );
+    }
+
+    #[test]
+    fn can_compare_to_baseline() {
+        assert_eq!(record(10, 90).change(&record(20, 80)), 0.0);
+        assert_eq!(record(10, 140).change(&record(20, 80)), 50.0);
+        assert_eq!(record(10, 40).change(&record(20, 80)), -50.0);
+    }
+}
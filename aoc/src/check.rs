@@ -0,0 +1,145 @@
+use std::fmt::Display;
+
+use aoc_core::{Answer, Registry};
+
+use crate::{
+    answers::AnswerDb,
+    cli::CheckArgs,
+    error::{self, Error},
+    input::InputCache,
+    target,
+};
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Status {
+    Pass,
+    Fail,
+    New,
+    Missing,
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let status = match self {
+            Self::Pass => "pass",
+            Self::Fail => "FAIL",
+            Self::New => "new",
+            Self::Missing => "missing",
+        };
+        f.pad(status)
+    }
+}
+
+fn print_row(year: u16, day: u8, part: u8, input: &str, status: Status, details: &str) {
+    println!("{year:<4}  {day:>3}  {part:>4}  {input:<10}  {status:<7}  {details}");
+}
+
+/// Grids don't fit in a table cell, so they get a short description and are printed below the row.
+fn describe(answer: &Answer) -> String {
+    match answer {
+        Answer::Grid(rows) => format!(
+            "{}x{} grid",
+            rows.iter().map(|row| row.len()).max().unwrap_or(0),
+            rows.len()
+        ),
+        answer => answer.to_string(),
+    }
+}
+
+fn print_grid(answer: &Answer) {
+    if let Answer::Grid(rows) = answer {
+        rows.iter().for_each(|row| println!("    {row}"));
+    }
+}
+
+pub fn check(registry: &Registry, cache: &InputCache, args: &CheckArgs) -> Result<(), Error> {
+    let mut db = AnswerDb::load(&args.answers)?;
+    let mut counts = [0; 4];
+    let mut recorded = false;
+
+    println!("year  day  part  input       status   answer");
+
+    for target in target::targets(registry, &args.selection)? {
+        let (year, day) = (target.year, target.day);
+
+        let mut inputs = match &args.selection.input {
+            Some(input) => vec![input.clone()],
+            None => cache.names(year, day),
+        };
+        for input in db.inputs(year, day) {
+            if !inputs.contains(&input) {
+                inputs.push(input);
+            }
+        }
+
+        if inputs.is_empty() {
+            for &part in &target.parts {
+                print_row(year, day, part, "-", Status::Missing, "no input");
+                counts[Status::Missing as usize] += 1;
+            }
+            continue;
+        }
+
+        for name in inputs {
+            let Ok(input) = cache.get(year, day, &name) else {
+                for &part in &target.parts {
+                    print_row(year, day, part, &name, Status::Missing, "no input");
+                    counts[Status::Missing as usize] += 1;
+                }
+                continue;
+            };
+
+            let answers = error::catch_panic(year, day, || {
+                Ok(registry.run(year, day, &target.parts, &input)?)
+            });
+
+            for (i, &part) in target.parts.iter().enumerate() {
+                let expected = db.get(year, day, &name, part).cloned();
+                let answer = match &answers {
+                    Ok(answers) => answers[i].clone().map_err(|err| err.to_string()),
+                    Err(err) => Err(err.to_string()),
+                };
+
+                match (answer, expected) {
+                    (Ok(answer), Some(expected)) if answer == expected => {
+                        print_row(year, day, part, &name, Status::Pass, &describe(&answer));
+                        counts[Status::Pass as usize] += 1;
+                    }
+                    (Ok(answer), Some(expected)) => {
+                        let details =
+                            format!("{} (expected {})", describe(&answer), describe(&expected));
+                        print_row(year, day, part, &name, Status::Fail, &details);
+                        print_grid(&answer);
+                        print_grid(&expected);
+                        counts[Status::Fail as usize] += 1;
+                    }
+                    (Ok(answer), None) => {
+                        print_row(year, day, part, &name, Status::New, &describe(&answer));
+                        print_grid(&answer);
+                        if args.record {
+                            db.set(year, day, &name, part, answer);
+                            recorded = true;
+                        }
+                        counts[Status::New as usize] += 1;
+                    }
+                    (Err(err), _) => {
+                        print_row(year, day, part, &name, Status::Fail, &err);
+                        counts[Status::Fail as usize] += 1;
+                    }
+                }
+            }
+        }
+    }
+
+    if recorded {
+        db.save(&args.answers)?;
+    }
+
+    let [pass, fail, new, missing] = counts;
+    println!("\n{pass} pass, {fail} fail, {new} new, {missing} missing");
+
+    match fail {
+        0 => Ok(()),
+        fail => Err(Error::Failed(fail)),
+    }
+}
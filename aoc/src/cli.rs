@@ -0,0 +1,146 @@
+use std::{path::PathBuf, str::FromStr};
+
+use clap::{Args, Parser, Subcommand, ValueEnum};
+
+#[derive(Parser)]
+#[command(name = "aoc", version, about = "Advent of Code solutions")]
+pub struct Cli {
+    #[command(subcommand)]
+    pub command: Command,
+    /// Directory the puzzle inputs are cached in
+    #[arg(long, global = true, env = "AOC_INPUT_DIR", default_value = "input")]
+    pub input_dir: PathBuf,
+    /// Never download missing inputs, even if AOC_SESSION is set
+    #[arg(long, global = true)]
+    pub offline: bool,
+    /// Copy missing inputs from a local directory laid out as YEAR/dayDAY.txt instead of downloading them
+    #[arg(long, global = true, value_name = "DIR")]
+    pub fetch_from: Option<PathBuf>,
+    /// Contact details sent in the User-Agent when downloading inputs
+    #[arg(long, global = true, env = "AOC_CONTACT")]
+    pub contact: Option<String>,
+}
+
+#[derive(Subcommand)]
+pub enum Command {
+    /// Run solutions and print their answers
+    Run(Selection),
+    /// Time how long solutions take
+    Bench(BenchArgs),
+    /// Compare answers against the ones recorded in the answer database
+    Check(CheckArgs),
+    /// Write a puzzle input out in a format other tools can visualise
+    Export(ExportArgs),
+    /// List the registered solutions
+    List {
+        /// Year to list, or 'all'
+        #[arg(short, long, default_value = "all")]
+        year: Select<u16>,
+    },
+}
+
+#[derive(Args)]
+pub struct Selection {
+    /// Year to run, or 'all'
+    #[arg(short, long, default_value = "all")]
+    pub year: Select<u16>,
+    /// Day to run, or 'all'
+    #[arg(short, long, default_value = "all")]
+    pub day: Select<u8>,
+    /// Part to run, or 'all'
+    #[arg(short, long, default_value = "all")]
+    pub part: Select<u8>,
+    /// Named input to use from the cache [default: 'default', or every cached input for check]
+    #[arg(short, long)]
+    pub input: Option<String>,
+}
+
+#[derive(Args)]
+pub struct BenchArgs {
+    #[command(flatten)]
+    pub selection: Selection,
+    /// How many times to run each part
+    #[arg(short = 'n', long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+    pub runs: u32,
+    /// Write the timings as JSON to this file
+    #[arg(long, value_name = "FILE")]
+    pub json: Option<PathBuf>,
+    /// Compare against timings previously written with --json
+    #[arg(long, value_name = "FILE")]
+    pub baseline: Option<PathBuf>,
+    /// Percentage the median may grow over the baseline before it counts as a regression
+    #[arg(long, default_value_t = 10.0)]
+    pub threshold: f64,
+}
+
+#[derive(Args)]
+pub struct CheckArgs {
+    #[command(flatten)]
+    pub selection: Selection,
+    /// Answer database to check against
+    #[arg(long, default_value = "answers.json")]
+    pub answers: PathBuf,
+    /// Record answers that are not in the database yet
+    #[arg(long)]
+    pub record: bool,
+}
+
+#[derive(Args)]
+pub struct ExportArgs {
+    /// What to export
+    pub kind: ExportKind,
+    /// Named input to export from the cache
+    #[arg(short, long, default_value = "default")]
+    pub input: String,
+    /// File to write to instead of stdout
+    #[arg(short, long, value_name = "FILE")]
+    pub output: Option<PathBuf>,
+    /// Button presses to record [default: 1000 for pulses, 10000 for flip-flops]
+    #[arg(long)]
+    pub presses: Option<usize>,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum ExportKind {
+    /// 2023 day 25 components and wires as a Graphviz graph
+    Wiring,
+    /// 2023 day 19 workflows as one simplified decision tree
+    Workflows,
+    /// 2023 day 20 pulses as a Value Change Dump
+    Pulses,
+    /// 2023 day 20 flip-flop periods as text
+    FlipFlops,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Select<T> {
+    All,
+    One(T),
+}
+
+impl<T: FromStr> FromStr for Select<T> {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "all" => Ok(Self::All),
+            value => value
+                .parse()
+                .map(Self::One)
+                .map_err(|_| format!("expected a number or 'all', got '{value}'")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_parse_select() {
+        assert_eq!("all".parse::<Select<u8>>(), Ok(Select::All));
+        assert_eq!("12".parse::<Select<u8>>(), Ok(Select::One(12)));
+        assert!("1a".parse::<Select<u8>>().is_err());
+        assert!("300".parse::<Select<u8>>().is_err());
+    }
+}
@@ -0,0 +1,105 @@
+use std::{
+    fmt::Display,
+    io,
+    panic::{self, AssertUnwindSafe},
+    path::PathBuf,
+    sync::{Arc, Mutex},
+};
+
+#[derive(Debug)]
+pub enum Error {
+    MissingInput(PathBuf, io::Error),
+    Fetch(u16, u8, String),
+    Cache(PathBuf, io::Error),
+    Answers(PathBuf, String),
+    Bench(PathBuf, String),
+    Export(Option<PathBuf>, io::Error),
+    Output(io::Error),
+    Registry(aoc_core::Error),
+    Solve(u16, u8, u8, aoc_core::SolveError),
+    Panicked(u16, u8, String),
+    UnknownYear(u16),
+    Failed(usize),
+    Regressed(usize),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MissingInput(path, err) => {
+                write!(f, "could not read input {}: {err}", path.display())
+            }
+            Self::Fetch(year, day, err) => {
+                write!(f, "could not fetch input for {year} day {day}: {err}")
+            }
+            Self::Cache(path, err) => write!(f, "could not write {}: {err}", path.display()),
+            Self::Answers(path, err) => write!(f, "answer database {}: {err}", path.display()),
+            Self::Bench(path, err) => write!(f, "bench results {}: {err}", path.display()),
+            Self::Export(Some(path), err) => write!(f, "could not write {}: {err}", path.display()),
+            Self::Export(None, err) => write!(f, "could not write export: {err}"),
+            Self::Output(err) => write!(f, "could not write output: {err}"),
+            Self::Registry(err) => write!(f, "{err}"),
+            Self::Solve(year, day, part, err) => write!(f, "{year} day {day} part {part}: {err}"),
+            Self::Panicked(year, day, message) => write!(f, "{year} day {day} panicked: {message}"),
+            Self::UnknownYear(year) => write!(f, "no solutions registered for {year}"),
+            Self::Failed(count) => write!(f, "{count} solution(s) failed"),
+            Self::Regressed(count) => write!(f, "{count} solution(s) got slower than the baseline"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<aoc_core::Error> for Error {
+    fn from(err: aoc_core::Error) -> Self {
+        Self::Registry(err)
+    }
+}
+
+/// Runs a day's solution, turning a panic into an error so one broken day doesn't take down a
+/// whole run. The panic is reported once, with where it happened, instead of by the default hook.
+pub fn catch_panic<T>(
+    year: u16,
+    day: u8,
+    run: impl FnOnce() -> Result<T, Error>,
+) -> Result<T, Error> {
+    let location = Arc::new(Mutex::new(None));
+    let recorded = Arc::clone(&location);
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+        *recorded.lock().unwrap() = info.location().map(|location| location.to_string());
+    }));
+
+    let result = panic::catch_unwind(AssertUnwindSafe(run));
+    panic::set_hook(hook);
+
+    result.unwrap_or_else(|payload| {
+        let mut message = match (
+            payload.downcast_ref::<&str>(),
+            payload.downcast_ref::<String>(),
+        ) {
+            (Some(message), _) => message.to_string(),
+            (_, Some(message)) => message.clone(),
+            _ => String::from("no message"),
+        };
+        if let Some(location) = location.lock().unwrap().take() {
+            message = format!("{message} at {location}");
+        }
+
+        Err(Error::Panicked(year, day, message))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_catch_panics() {
+        let result: Result<(), Error> = catch_panic(2022, 1, || panic!("bad input"));
+        let message = result.unwrap_err().to_string();
+
+        assert!(message.starts_with("2022 day 1 panicked: bad input at aoc/src/error.rs:"));
+        assert_eq!(catch_panic(2022, 1, || Ok(5)).unwrap(), 5);
+    }
+}
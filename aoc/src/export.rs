@@ -0,0 +1,39 @@
+use std::{
+    fs::File,
+    io::{self, BufWriter, Write},
+};
+
+use crate::{
+    cli::{ExportArgs, ExportKind},
+    error::Error,
+    input::InputCache,
+};
+
+fn write_export(args: &ExportArgs, input: &str, out: &mut impl Write) -> io::Result<()> {
+    match args.kind {
+        ExportKind::Wiring => aoc2023::day25::write_dot(input, out)?,
+        ExportKind::Workflows => aoc2023::day19::write_decisions(input, out)?,
+        ExportKind::Pulses => aoc2023::day20::write_vcd(input, args.presses.unwrap_or(1000), out)?,
+        ExportKind::FlipFlops => {
+            aoc2023::day20::write_flip_flop_periods(input, args.presses.unwrap_or(10000), out)?
+        }
+    }
+
+    out.flush()
+}
+
+pub fn export(cache: &InputCache, args: &ExportArgs) -> Result<(), Error> {
+    let input = match args.kind {
+        ExportKind::Wiring => cache.get(2023, 25, &args.input)?,
+        ExportKind::Workflows => cache.get(2023, 19, &args.input)?,
+        ExportKind::Pulses | ExportKind::FlipFlops => cache.get(2023, 20, &args.input)?,
+    };
+
+    let result = match &args.output {
+        Some(path) => File::create(path)
+            .and_then(|file| write_export(args, &input, &mut BufWriter::new(file))),
+        None => write_export(args, &input, &mut io::stdout().lock()),
+    };
+
+    result.map_err(|err| Error::Export(args.output.clone(), err))
+}
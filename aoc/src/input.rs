@@ -0,0 +1,136 @@
+mod fetch;
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use crate::error::Error;
+
+pub use self::fetch::{DirFetcher, Fetcher, HttpFetcher};
+
+/// Name of the input that belongs to the account behind `AOC_SESSION`.
+pub const DEFAULT_INPUT: &str = "default";
+
+/// Puzzle inputs stored as `{dir}/{year}/day{day}/{name}.txt`.
+///
+/// Only the default input can be fetched, other names (e.g. a teammate's input)
+/// have to be put into the cache by hand.
+pub struct InputCache {
+    dir: PathBuf,
+    fetcher: Option<Box<dyn Fetcher>>,
+}
+
+impl InputCache {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self {
+            dir: dir.into(),
+            fetcher: None,
+        }
+    }
+
+    pub fn with_fetcher(mut self, fetcher: impl Fetcher + 'static) -> Self {
+        self.fetcher = Some(Box::new(fetcher));
+        self
+    }
+
+    fn day_dir(&self, year: u16, day: u8) -> PathBuf {
+        self.dir.join(year.to_string()).join(format!("day{day}"))
+    }
+
+    pub fn path(&self, year: u16, day: u8, name: &str) -> PathBuf {
+        self.day_dir(year, day).join(format!("{name}.txt"))
+    }
+
+    pub fn names(&self, year: u16, day: u8) -> Vec<String> {
+        let Ok(entries) = fs::read_dir(self.day_dir(year, day)) else {
+            return Vec::new();
+        };
+
+        let mut names: Vec<String> = entries
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+            .filter(|path| path.extension().is_some_and(|ext| ext == "txt"))
+            .filter_map(|path| Some(path.file_stem()?.to_str()?.to_owned()))
+            .collect();
+        names.sort();
+        names
+    }
+
+    pub fn get(&self, year: u16, day: u8, name: &str) -> Result<String, Error> {
+        let path = self.path(year, day, name);
+
+        match fs::read_to_string(&path) {
+            Ok(input) => Ok(input),
+            Err(err) => match &self.fetcher {
+                Some(fetcher) if name == DEFAULT_INPUT => {
+                    let input = fetcher.fetch(year, day)?;
+                    self.store(&path, &input)?;
+                    Ok(input)
+                }
+                _ => Err(Error::MissingInput(path, err)),
+            },
+        }
+    }
+
+    fn store(&self, path: &Path, input: &str) -> Result<(), Error> {
+        fs::create_dir_all(path.parent().unwrap())
+            .and_then(|_| fs::write(path, input))
+            .map_err(|err| Error::Cache(path.to_owned(), err))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc-input-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn fetches_missing_default_input_once() {
+        let fixtures = temp_dir("fixtures");
+        fs::create_dir_all(fixtures.join("2022")).unwrap();
+        fs::write(fixtures.join("2022/day1.txt"), "1\n2\n").unwrap();
+
+        let cache_dir = temp_dir("fetch");
+        let cache = InputCache::new(&cache_dir).with_fetcher(DirFetcher::new(&fixtures));
+
+        assert_eq!(cache.get(2022, 1, DEFAULT_INPUT).unwrap(), "1\n2\n");
+        assert!(cache.path(2022, 1, DEFAULT_INPUT).exists());
+
+        fs::remove_dir_all(&fixtures).unwrap();
+        assert_eq!(cache.get(2022, 1, DEFAULT_INPUT).unwrap(), "1\n2\n");
+        assert!(matches!(
+            cache.get(2022, 2, DEFAULT_INPUT),
+            Err(Error::Fetch(2022, 2, _))
+        ));
+
+        fs::remove_dir_all(&cache_dir).unwrap();
+    }
+
+    #[test]
+    fn lists_named_inputs() {
+        let cache_dir = temp_dir("names");
+        let cache = InputCache::new(&cache_dir).with_fetcher(DirFetcher::new(&cache_dir));
+
+        for name in ["default", "bob"] {
+            let path = cache.path(2023, 5, name);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, name).unwrap();
+        }
+
+        assert_eq!(cache.names(2023, 5), vec!["bob", "default"]);
+        assert_eq!(cache.get(2023, 5, "bob").unwrap(), "bob");
+        assert!(matches!(
+            cache.get(2023, 5, "alice"),
+            Err(Error::MissingInput(_, _))
+        ));
+        assert!(cache.names(2023, 6).is_empty());
+
+        fs::remove_dir_all(&cache_dir).unwrap();
+    }
+}
@@ -0,0 +1,66 @@
+use std::{fs, path::PathBuf};
+
+use crate::error::Error;
+
+/// Source of puzzle inputs that are not in the cache yet.
+pub trait Fetcher {
+    fn fetch(&self, year: u16, day: u8) -> Result<String, Error>;
+}
+
+pub struct HttpFetcher {
+    base_url: String,
+    session: String,
+    user_agent: String,
+}
+
+impl HttpFetcher {
+    pub fn new(session: &str) -> Self {
+        Self {
+            base_url: String::from("https://adventofcode.com"),
+            session: session.to_owned(),
+            user_agent: format!("aoc/{}", env!("CARGO_PKG_VERSION")),
+        }
+    }
+
+    /// Adds a way to reach whoever runs this, like an email address or repository url, to the
+    /// User-Agent so the site can get in touch about the traffic.
+    pub fn with_contact(mut self, contact: &str) -> Self {
+        self.user_agent = format!("aoc/{} ({contact})", env!("CARGO_PKG_VERSION"));
+        self
+    }
+}
+
+impl Fetcher for HttpFetcher {
+    fn fetch(&self, year: u16, day: u8) -> Result<String, Error> {
+        let url = format!("{}/{year}/day/{day}/input", self.base_url);
+
+        ureq::get(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .set("User-Agent", &self.user_agent)
+            .call()
+            .map_err(|err| Error::Fetch(year, day, err.to_string()))?
+            .into_string()
+            .map_err(|err| Error::Fetch(year, day, err.to_string()))
+    }
+}
+
+/// Serves inputs from a local directory laid out as `{year}/day{day}.txt`.
+pub struct DirFetcher {
+    dir: PathBuf,
+}
+
+impl DirFetcher {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+}
+
+impl Fetcher for DirFetcher {
+    fn fetch(&self, year: u16, day: u8) -> Result<String, Error> {
+        let path = self
+            .dir
+            .join(year.to_string())
+            .join(format!("day{day}.txt"));
+        fs::read_to_string(&path).map_err(|err| Error::Fetch(year, day, err.to_string()))
+    }
+}
@@ -0,0 +1,33 @@
+use std::io::{self, Write};
+
+use aoc_core::Registry;
+
+use crate::{cli::Select, error::Error, input::InputCache, target};
+
+pub fn list(registry: &Registry, cache: &InputCache, year: Select<u16>) -> Result<(), Error> {
+    let mut out = io::stdout().lock();
+
+    for year in target::years(registry, year)? {
+        for day in registry.days(year) {
+            let parts = registry.get(year, day)?.parts();
+            let inputs = cache.names(year, day);
+
+            let written = writeln!(
+                out,
+                "{year} day {day:>2}  {parts} part(s)  inputs: {}",
+                match inputs.is_empty() {
+                    true => String::from("-"),
+                    false => inputs.join(", "),
+                }
+            );
+
+            // piping into something like `head` closes stdout early, which just ends the list
+            match written {
+                Err(err) if err.kind() == io::ErrorKind::BrokenPipe => return Ok(()),
+                written => written.map_err(Error::Output)?,
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,55 @@
+mod answers;
+mod bench;
+mod check;
+mod cli;
+mod error;
+mod export;
+mod input;
+mod list;
+mod run;
+mod target;
+
+use std::{env, process::ExitCode};
+
+use aoc_core::Registry;
+use clap::Parser;
+
+use self::{
+    cli::{Cli, Command},
+    input::{DirFetcher, HttpFetcher, InputCache},
+};
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    let mut registry = Registry::new();
+    aoc2022::register(&mut registry);
+    aoc2023::register(&mut registry);
+
+    let mut cache = InputCache::new(&cli.input_dir);
+    if let Some(dir) = &cli.fetch_from {
+        cache = cache.with_fetcher(DirFetcher::new(dir));
+    } else if let (false, Ok(session)) = (cli.offline, env::var("AOC_SESSION")) {
+        let fetcher = HttpFetcher::new(&session);
+        cache = cache.with_fetcher(match &cli.contact {
+            Some(contact) => fetcher.with_contact(contact),
+            None => fetcher,
+        });
+    }
+
+    let result = match &cli.command {
+        Command::Run(selection) => run::run(&registry, &cache, selection),
+        Command::Bench(args) => bench::bench(&registry, &cache, args),
+        Command::Check(args) => check::check(&registry, &cache, args),
+        Command::Export(args) => export::export(&cache, args),
+        Command::List { year } => list::list(&registry, &cache, *year),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
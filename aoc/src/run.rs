@@ -0,0 +1,55 @@
+use aoc_core::Registry;
+
+use crate::{
+    cli::{Select, Selection},
+    error::{self, Error},
+    input::{InputCache, DEFAULT_INPUT},
+    target::{self, Target},
+};
+
+fn run_target(registry: &Registry, input: &str, target: &Target) -> Result<(), Error> {
+    let answers = error::catch_panic(target.year, target.day, || {
+        Ok(registry.run(target.year, target.day, &target.parts, input)?)
+    })?;
+    for (part, answer) in target.parts.iter().zip(answers) {
+        let answer = answer.map_err(|err| Error::Solve(target.year, target.day, *part, err))?;
+        println!("{answer}");
+    }
+
+    Ok(())
+}
+
+pub fn run(registry: &Registry, cache: &InputCache, selection: &Selection) -> Result<(), Error> {
+    let input_name = selection.input.as_deref().unwrap_or(DEFAULT_INPUT);
+    let mut failed = 0;
+    let mut year = None;
+
+    for target in target::targets(registry, selection)? {
+        if selection.year == Select::All && year != Some(target.year) {
+            year = Some(target.year);
+            println!("Year {}:", target.year);
+        }
+
+        if selection.day == Select::All {
+            println!("Day {}:", target.day);
+        }
+
+        let result = cache
+            .get(target.year, target.day, input_name)
+            .and_then(|input| run_target(registry, &input, &target));
+
+        if let Err(err) = result {
+            eprintln!("error: {err}");
+            failed += 1;
+        }
+
+        if selection.day == Select::All {
+            println!();
+        }
+    }
+
+    match failed {
+        0 => Ok(()),
+        failed => Err(Error::Failed(failed)),
+    }
+}
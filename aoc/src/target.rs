@@ -0,0 +1,48 @@
+use aoc_core::Registry;
+
+use crate::{
+    cli::{Select, Selection},
+    error::Error,
+};
+
+pub struct Target {
+    pub year: u16,
+    pub day: u8,
+    pub parts: Vec<u8>,
+}
+
+pub fn years(registry: &Registry, year: Select<u16>) -> Result<Vec<u16>, Error> {
+    match year {
+        Select::All => Ok(registry.years()),
+        Select::One(year) if registry.days(year).is_empty() => Err(Error::UnknownYear(year)),
+        Select::One(year) => Ok(vec![year]),
+    }
+}
+
+pub fn targets(registry: &Registry, selection: &Selection) -> Result<Vec<Target>, Error> {
+    let mut targets = Vec::new();
+
+    for year in years(registry, selection.year)? {
+        let days = match selection.day {
+            Select::All => registry.days(year),
+            Select::One(day) => vec![day],
+        };
+
+        for day in days {
+            let available = registry.get(year, day)?.parts();
+            let parts = match selection.part {
+                Select::All => (1..=available).collect(),
+                Select::One(part) if (1..=available).contains(&part) => vec![part],
+                // a whole year asked for part 2, so day 25 is simply skipped
+                Select::One(_) if selection.day == Select::All => continue,
+                Select::One(part) => {
+                    return Err(aoc_core::Error::InvalidPart(year, day, part).into())
+                }
+            };
+
+            targets.push(Target { year, day, parts });
+        }
+    }
+
+    Ok(targets)
+}
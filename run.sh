@@ -122,9 +122,26 @@ function runDay() {
   [[ $PART == "all" ]] || [ "$PART" -eq "2" ] && [ "$day" -lt "$lastDay" ] && ./run.sh $day 2
 }
 
+function runCargoYear() {
+  year=$1
+
+  pushd "$SCRIPT_DIR" > $LOG_OUTPUT
+
+  cargo build --release --quiet -p aoc > $LOG_OUTPUT
+
+  AOC_SESSION="$AOC_SESSION" ./target/release/aoc run --year $year --day $DAY --part $PART
+
+  popd > $LOG_OUTPUT
+}
+
 function runYear() {
   year=$1
 
+  if [ -f "$SCRIPT_DIR/$year/Cargo.toml" ]; then
+    runCargoYear $year
+    return
+  fi
+
   pushd "$SCRIPT_DIR/$year" > $LOG_OUTPUT
 
   [ ! -f "./run.sh" ] && echo "Not solved yet" && return